@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// The answer of one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(u128),
+    Text(String),
+    /// The part has no puzzle, like the second part of the last day.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as u128)
+                }
+            }
+        )*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[test]
+fn display_answer() {
+    assert_eq!(Answer::from(16927usize).to_string(), "16927");
+    assert_eq!(Answer::from(4765757080u128).to_string(), "4765757080");
+    assert_eq!(Answer::from("abc").to_string(), "abc");
+    assert_eq!(Answer::Unsolved.to_string(), "-");
+}
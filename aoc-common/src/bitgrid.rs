@@ -0,0 +1,223 @@
+//! Grids of cells that are either on or off, for simulations over whole
+//! grids.
+//!
+//! A [`BitGrid`] packs every row into `u64` words, column `c` in bit `c % 64`
+//! of word `c / 64`, so rows are shifted, combined and counted 64 cells at a
+//! time instead of cell by cell. Bits right of the last column are always 0.
+
+use crate::grid::{Grid, Pos};
+
+/// Bits in a word.
+const BITS: usize = u64::BITS as usize;
+
+/// A row of cells packed into words, as in [`BitGrid::row`].
+pub fn pack(cells: impl IntoIterator<Item = bool>) -> Vec<u64> {
+    let mut words = vec![];
+    for (col, cell) in cells.into_iter().enumerate() {
+        if col % BITS == 0 {
+            words.push(0);
+        }
+        words[col / BITS] |= (cell as u64) << (col % BITS);
+    }
+    words
+}
+
+/// Word `i` of `row` with every cell replaced by its neighbour to the left
+/// and by its neighbour to the right, off past the ends of the row.
+pub fn shifted(row: &[u64], i: usize) -> (u64, u64) {
+    let left = row[i] << 1 | i.checked_sub(1).map_or(0, |j| row[j] >> (BITS - 1));
+    let right = row[i] >> 1 | row.get(i + 1).map_or(0, |w| w << (BITS - 1));
+    (left, right)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Words in a row.
+    words: usize,
+    bits: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A `width` by `height` grid with every cell off.
+    pub fn new(width: usize, height: usize) -> Self {
+        let words = width.div_ceil(BITS);
+        Self {
+            width,
+            height,
+            words,
+            bits: vec![0; words * height],
+        }
+    }
+
+    /// The cells of `grid` for which `f` holds.
+    pub fn matching<T>(grid: &Grid<T>, mut f: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.width(), grid.height());
+        for (row, cells) in grid.rows().enumerate() {
+            bits.row_mut(row)
+                .copy_from_slice(&pack(cells.iter().map(&mut f)));
+        }
+        bits
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        let mut grid = Grid::new(self.width, self.height, false);
+        for pos in grid.positions() {
+            grid[pos] = self.get(pos);
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn word(&self, pos: Pos) -> usize {
+        assert!(
+            pos.row < self.height && pos.col < self.width,
+            "{pos} outside of the grid"
+        );
+        pos.row * self.words + pos.col / BITS
+    }
+
+    pub fn get(&self, pos: Pos) -> bool {
+        self.bits[self.word(pos)] >> (pos.col % BITS) & 1 == 1
+    }
+
+    pub fn set(&mut self, pos: Pos, on: bool) {
+        let word = self.word(pos);
+        let bit = 1 << (pos.col % BITS);
+        if on {
+            self.bits[word] |= bit;
+        } else {
+            self.bits[word] &= !bit;
+        }
+    }
+
+    /// The words of `row`.
+    pub fn row(&self, row: usize) -> &[u64] {
+        &self.bits[row * self.words..(row + 1) * self.words]
+    }
+
+    /// The words of `row`, bits right of the last column must stay 0.
+    pub fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        &mut self.bits[row * self.words..(row + 1) * self.words]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[u64]> + ExactSizeIterator {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Cells that are on.
+    pub fn count_ones(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Set `out` to the cells of `row`, on or off, with fewer than
+    /// `threshold` of their 8 neighbours on.
+    ///
+    /// The 8 neighbours of 64 cells are added at once, the count of every
+    /// cell kept in 4 words holding one bit of it each, then compared with
+    /// `threshold` from the highest bit down.
+    pub fn fewer_neighbours_in_row(&self, row: usize, threshold: usize, out: &mut [u64]) {
+        let above = row.checked_sub(1).map(|r| self.row(r));
+        let below = (row + 1 < self.height).then(|| self.row(row + 1));
+        let current = self.row(row);
+        for (i, out) in out.iter_mut().enumerate().take(self.words) {
+            let mut count = [0u64; 4];
+            let mut add = |mut carry: u64| {
+                for bit in &mut count {
+                    (*bit, carry) = (*bit ^ carry, *bit & carry);
+                }
+            };
+            for row in [above, below].into_iter().flatten() {
+                let (left, right) = shifted(row, i);
+                add(left);
+                add(row[i]);
+                add(right);
+            }
+            let (left, right) = shifted(current, i);
+            add(left);
+            add(right);
+
+            *out = if threshold >= 1 << count.len() {
+                !0
+            } else {
+                let (mut less, mut equal) = (0, !0);
+                for (b, bit) in count.iter().enumerate().rev() {
+                    if threshold >> b & 1 == 1 {
+                        less |= equal & !bit;
+                        equal &= bit;
+                    } else {
+                        equal &= !bit;
+                    }
+                }
+                less
+            };
+        }
+        if let Some(last) = out[..self.words].last_mut()
+            && !self.width.is_multiple_of(BITS)
+        {
+            *last &= (1 << (self.width % BITS)) - 1;
+        }
+    }
+
+    /// The cells, on or off, with fewer than `threshold` of their 8
+    /// neighbours on.
+    pub fn fewer_neighbours(&self, threshold: usize) -> Self {
+        let mut fewer = Self::new(self.width, self.height);
+        for row in 0..self.height {
+            let words = row * self.words..(row + 1) * self.words;
+            self.fewer_neighbours_in_row(row, threshold, &mut fewer.bits[words]);
+        }
+        fewer
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        Self::matching(grid, |&cell| cell)
+    }
+}
+
+#[test]
+fn fewer_neighbours() {
+    let mut rng = crate::fuzz::Rng::new(4);
+    let mut grid = Grid::new(130, 7, false);
+    for pos in grid.positions() {
+        grid[pos] = rng.chance(0.6);
+    }
+    let bits = BitGrid::from(&grid);
+    assert_eq!(bits.to_grid(), grid);
+    assert_eq!(
+        bits.count_ones(),
+        grid.iter().filter(|&(_, &on)| on).count()
+    );
+    for threshold in 0..=10 {
+        let fewer = bits.fewer_neighbours(threshold);
+        for pos in grid.positions() {
+            let on = grid.neighbours8(pos).filter(|&n| grid[n]).count();
+            assert_eq!(fewer.get(pos), on < threshold, "{pos}, {threshold}");
+        }
+    }
+}
+
+#[test]
+fn pack_and_shift() {
+    let row = pack((0..70).map(|col| col == 0 || col == 63 || col == 69));
+    assert_eq!(row, [1 | 1 << 63, 1 << 5]);
+    assert_eq!(shifted(&row, 0), (1 << 1, 1 << 62));
+    assert_eq!(shifted(&row, 1), (1 << 6 | 1, 1 << 4));
+
+    let mut bits = BitGrid::new(70, 2);
+    bits.set(Pos::new(1, 69), true);
+    assert_eq!(bits.row(1), [0, 1 << 5]);
+    assert!(bits.get(Pos::new(1, 69)) && !bits.get(Pos::new(0, 69)));
+    bits.set(Pos::new(1, 69), false);
+    assert_eq!(bits.count_ones(), 0);
+}
@@ -0,0 +1,23 @@
+//! Helpers for the build scripts of the days.
+
+/// Set `cfg(missing_input)` when the personal input of the crate being built
+/// is absent or blank, so `real_input` tests can be reported as ignored
+/// instead of failing. The day is taken from the package name, e.g. `aoc07`.
+///
+/// The directory of the input is watched rather than the input itself, so
+/// the crate is built again once the input is added.
+pub fn input_cfg() {
+    println!("cargo::rustc-check-cfg=cfg(missing_input)");
+    println!("cargo::rerun-if-env-changed=AOC_INPUT_DIR");
+
+    let name = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
+    let Some(day) = name.strip_prefix("aoc").and_then(|d| d.parse::<u8>().ok()) else {
+        return;
+    };
+    println!("cargo::rerun-if-changed={}", crate::input_dir(day).display());
+    let path = crate::input_path(day);
+    let present = std::fs::read_to_string(&path).is_ok_and(|s| !s.trim().is_empty());
+    if !present {
+        println!("cargo::rustc-cfg=missing_input");
+    }
+}
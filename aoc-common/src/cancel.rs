@@ -0,0 +1,84 @@
+//! Cooperative cancellation of long-running parts.
+//!
+//! The runner solves parts on a worker thread with a [`Token`] installed as
+//! the token of that thread, and cancels it once the part ran out of time.
+//! Solvers with long loops take the token with [`current`] and call
+//! [`Token::check`] now and then, which fails with [`Cancelled`] once the part
+//! is cancelled. The token can be moved into other threads, e.g. rayon
+//! closures, where [`current`] would not see it.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The part was cancelled before it finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+#[derive(Debug, Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fail once cancelled.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// The token of the part running on this thread, one that is never cancelled
+/// outside of [`with_token`].
+pub fn current() -> Token {
+    CURRENT.with_borrow(|token| token.clone().unwrap_or_default())
+}
+
+/// Run `f` with `token` as the token of this thread.
+pub fn with_token<T>(token: &Token, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(Some(token.clone()));
+    let value = f();
+    CURRENT.set(previous);
+    value
+}
+
+#[test]
+fn cancel_current_token() {
+    assert!(current().check().is_ok());
+    let token = Token::new();
+    let cancelled = with_token(&token, || {
+        let current = current();
+        assert!(current.check().is_ok());
+        token.cancel();
+        current.check()
+    });
+    assert_eq!(cancelled, Err(Cancelled));
+    assert!(current().check().is_ok());
+}
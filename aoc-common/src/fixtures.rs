@@ -0,0 +1,188 @@
+//! Small inputs with known answers, usually the examples of the puzzle text.
+//!
+//! Every day keeps them in `aocNN/fixtures`, one `<name>.txt` per input and a
+//! shared `answers.toml` with the expected answers:
+//!
+//! ```toml
+//! [example-1]
+//! part1 = "3"
+//! part2 = "6"
+//! ```
+//!
+//! Parts without an expected answer are not checked. An example that uses
+//! other puzzle constants than the real input gives them as parameters, see
+//! [`crate::params`]:
+//!
+//! ```toml
+//! [example-1]
+//! part1 = "40"
+//!
+//! [example-1.params]
+//! pairs = 10
+//! ```
+//!
+//! A fixture the solution is known to get wrong, e.g. because it relies on
+//! a property of the real inputs that the example lacks, says why in
+//! `differs`. Its answers are then checked to differ from the expected ones,
+//! so the fixture is noticed once they match:
+//!
+//! ```toml
+//! [example-1]
+//! part1 = "2"
+//! differs = "the heuristic only holds for the real inputs"
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::params::Overrides;
+use crate::registry::Entry;
+use crate::{Answer, Part, Puzzle, Result, err, workspace_root};
+
+pub fn fixtures_dir(day: u8) -> PathBuf {
+    workspace_root()
+        .join(format!("aoc{day:02}"))
+        .join("fixtures")
+}
+
+pub struct Fixture {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    /// Parameters replacing the puzzle constants, empty for the real ones.
+    pub params: Overrides,
+    /// Why the answers are known to differ from the expected ones.
+    pub differs: Option<String>,
+}
+
+impl Fixture {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FixtureEntry {
+    #[serde(flatten)]
+    answers: Entry,
+    #[serde(default, skip_serializing_if = "Overrides::is_empty")]
+    params: Overrides,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    differs: Option<String>,
+}
+
+fn load_answers(day: u8) -> Result<BTreeMap<String, FixtureEntry>> {
+    let path = fixtures_dir(day).join("answers.toml");
+    match std::fs::read_to_string(&path) {
+        Ok(s) => Ok(toml::from_str(&s).map_err(|e| format!("{}: {e}", path.display()))?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => err!("unable to read {}: {e}", path.display()),
+    }
+}
+
+/// Every fixture of `day` that has at least one expected answer, by name.
+pub fn load(day: u8) -> Result<Vec<Fixture>> {
+    let dir = fixtures_dir(day);
+    let mut fixtures = vec![];
+    for (name, entry) in load_answers(day)? {
+        let path = dir.join(format!("{name}.txt"));
+        let input = std::fs::read_to_string(&path)
+            .map_err(|e| format!("unable to read {}: {e}", path.display()))?;
+        fixtures.push(Fixture {
+            name,
+            input,
+            part1: entry.answers.part1,
+            part2: entry.answers.part2,
+            params: entry.params,
+            differs: entry.differs,
+        });
+    }
+    Ok(fixtures)
+}
+
+/// Write `fixture` to the fixtures of `day`, replacing a fixture with the
+/// same name and keeping the others. A fixture without parameters or
+/// `differs` keeps those of the fixture it replaces.
+pub fn save(day: u8, fixture: &Fixture) -> Result<PathBuf> {
+    let dir = fixtures_dir(day);
+    std::fs::create_dir_all(&dir)?;
+    let mut answers = load_answers(day)?;
+    let previous = answers.remove(&fixture.name).unwrap_or_default();
+    let params = if fixture.params.is_empty() {
+        previous.params
+    } else {
+        fixture.params.clone()
+    };
+    let differs = fixture.differs.clone().or(previous.differs);
+    answers.insert(
+        fixture.name.clone(),
+        FixtureEntry {
+            answers: Entry {
+                part1: fixture.part1.clone(),
+                part2: fixture.part2.clone(),
+            },
+            params,
+            differs,
+        },
+    );
+    let path = dir.join(format!("{}.txt", fixture.name));
+    std::fs::write(&path, &fixture.input)?;
+    std::fs::write(dir.join("answers.toml"), toml::to_string(&answers)?)?;
+    Ok(path)
+}
+
+/// Solve every fixture of `puzzle` with the main and the alternate
+/// implementations, failing on the first answer that differs from the
+/// expected one, or that matches it for a fixture marked `differs`. Returns
+/// how many answers were checked.
+pub fn check(puzzle: &Puzzle) -> Result<usize> {
+    let mut checked = 0;
+    for fixture in load(puzzle.day())? {
+        let parsed = puzzle
+            .parse_with(&fixture.input, &fixture.params)
+            .map_err(|e| format!("aoc{:02} {}: {e}", puzzle.day(), fixture.name))?;
+        for part in Part::ALL {
+            let Some(expected) = fixture.expected(part) else {
+                continue;
+            };
+            let mut answers = vec![("main", parsed.solve(part)?)];
+            for (_, name) in parsed
+                .alternates(true)
+                .into_iter()
+                .filter(|(p, _)| *p == part)
+            {
+                if let Some(answer) = parsed.solve_alternate(part, name) {
+                    answers.push((name, answer?));
+                }
+            }
+            for (name, answer) in answers {
+                let right = answer != Answer::Unsolved && answer.to_string() == expected;
+                match (&fixture.differs, right) {
+                    (None, false) => {
+                        return err!(
+                            "aoc{:02} {} part {part} ({name}): got {answer}, expected {expected}",
+                            puzzle.day(),
+                            fixture.name
+                        );
+                    }
+                    (Some(why), true) => {
+                        return err!(
+                            "aoc{:02} {} part {part} ({name}): got {answer} as expected, \
+                             drop `differs = {why:?}`",
+                            puzzle.day(),
+                            fixture.name
+                        );
+                    }
+                    _ => checked += 1,
+                }
+            }
+        }
+    }
+    Ok(checked)
+}
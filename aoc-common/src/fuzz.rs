@@ -0,0 +1,204 @@
+//! Randomized differential testing of the alternate implementations.
+//!
+//! Days generate random inputs with [`Solution::generate`], every part that
+//! has alternates is solved by all of them (slow ones included, generated
+//! inputs are small) and the answers are compared with the main
+//! implementation, which serves as the reference. A disagreement is shrunk to
+//! a small input that still disagrees, by removing lines, removing
+//! comma-separated items and making numbers smaller.
+//!
+//! [`Solution::generate`]: crate::Solution::generate
+
+use std::ops::RangeInclusive;
+use std::panic::AssertUnwindSafe;
+
+use crate::{Answer, Parsed, Part, Puzzle, Result};
+
+/// SplitMix64, small and good enough to generate puzzle inputs.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Uniform in `range` for sizes and indices.
+    pub fn index(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        self.range(start as u64..=end as u64) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+}
+
+/// Two implementations of a part disagreeing on an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub input: String,
+    pub part: Part,
+    pub name: &'static str,
+    pub main: Answer,
+    pub alternate: Answer,
+}
+
+/// The first alternate disagreeing with the main implementation on `input`.
+/// Inputs that do not parse or make any implementation fail have no
+/// disagreement.
+fn disagreement(puzzle: &Puzzle, input: &str) -> Option<Disagreement> {
+    let parsed: Box<dyn Parsed> = puzzle.parse(input).ok()?;
+    for part in Part::ALL {
+        let alternates: Vec<_> = parsed
+            .alternates(true)
+            .into_iter()
+            .filter(|(p, _)| *p == part)
+            .collect();
+        if alternates.is_empty() {
+            continue;
+        }
+        let main = parsed.solve(part).ok()?;
+        for (_, name) in alternates {
+            let alternate = parsed.solve_alternate(part, name)?.ok()?;
+            if alternate != main {
+                return Some(Disagreement {
+                    input: input.to_string(),
+                    part,
+                    name,
+                    main,
+                    alternate,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Smaller variants of `input`, the most aggressive first.
+fn candidates(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut candidates = vec![];
+
+    if lines.len() > 1 {
+        for i in 0..lines.len() {
+            let mut rest = lines.clone();
+            rest.remove(i);
+            candidates.push(rest.join("\n"));
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let items: Vec<&str> = line.split(',').collect();
+        if items.len() < 2 {
+            continue;
+        }
+        for j in 0..items.len() {
+            let mut rest = items.clone();
+            rest.remove(j);
+            let mut lines = lines.clone();
+            let line = rest.join(",");
+            lines[i] = &line;
+            candidates.push(lines.join("\n"));
+        }
+    }
+
+    let mut start = None;
+    for (i, c) in input.char_indices().chain([(input.len(), ' ')]) {
+        match (start, c.is_ascii_digit()) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                start = None;
+                let Ok(n) = input[s..i].parse::<u128>() else {
+                    continue;
+                };
+                let mut smaller = vec![0, n / 2, n.saturating_sub(1)];
+                smaller.dedup();
+                for m in smaller.into_iter().filter(|&m| m < n) {
+                    candidates.push(format!("{}{m}{}", &input[..s], &input[i..]));
+                }
+            }
+            _ => {}
+        }
+    }
+    candidates
+}
+
+/// Shrink a disagreement to an input where no smaller variant disagrees.
+/// Variants on which a solver panics (e.g. a line too short for it) are
+/// skipped, their panic messages still printed: the panic hook is shared by
+/// the whole process, e.g. by the other tests, and left alone.
+pub fn shrink(puzzle: &Puzzle, mut found: Disagreement) -> Disagreement {
+    'shrink: loop {
+        for candidate in candidates(&found.input) {
+            let smaller =
+                std::panic::catch_unwind(AssertUnwindSafe(|| disagreement(puzzle, &candidate)));
+            if let Ok(Some(smaller)) = smaller {
+                found = smaller;
+                continue 'shrink;
+            }
+        }
+        break;
+    }
+    found
+}
+
+/// Solve `runs` generated inputs, starting from `seed`, and return the first
+/// disagreement, shrunk. `None` when the day has no generator or all
+/// implementations agree.
+pub fn fuzz(puzzle: &Puzzle, seed: u64, runs: usize) -> Result<Option<Disagreement>> {
+    let mut rng = Rng::new(seed);
+    for _ in 0..runs {
+        let Some(input) = puzzle.generate(&mut rng) else {
+            return Ok(None);
+        };
+        // a generator must produce valid inputs
+        let parsed = puzzle
+            .parse(&input)
+            .map_err(|e| format!("generated input does not parse: {e}\n{input}"))?;
+        for part in Part::ALL {
+            parsed.solve(part)?;
+        }
+        if let Some(found) = disagreement(puzzle, &input) {
+            return Ok(Some(shrink(puzzle, found)));
+        }
+    }
+    Ok(None)
+}
+
+#[test]
+fn rng_is_deterministic() {
+    let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+    assert!((0..100).all(|_| a.next_u64() == b.next_u64()));
+    assert!((0..1000).all(|_| (3..=5).contains(&a.range(3..=5))));
+    assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+}
+
+#[test]
+fn shrink_to_minimal_input() -> Result<()> {
+    let puzzle = Puzzle::of::<crate::testing::Sum>();
+    let found = fuzz(&puzzle, 1, 100)?.expect("wrapping disagrees with the sum");
+    assert_eq!(found.input, "100");
+    assert_eq!((found.part, found.name), (Part::One, "wrapping"));
+    assert_eq!((found.main, found.alternate), (100u32.into(), 0u32.into()));
+    Ok(())
+}
@@ -0,0 +1,326 @@
+//! Rectangular grids of cells, like the diagrams of several days.
+//!
+//! A [`Grid`] stores its cells row after row in one `Vec` and is indexed by
+//! [`Pos`], a row and a column counted from the top left. Neighbours are only
+//! ever positions inside the grid, so days do not need to check bounds.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::input::{self, ParseError, ParseResult};
+
+/// Position of a cell, `row` counted down and `col` to the right from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The position `rows` down and `cols` to the right, `None` above or
+    /// left of the grid.
+    pub fn offset(self, rows: isize, cols: isize) -> Option<Self> {
+        Some(Self {
+            row: self.row.checked_add_signed(rows)?,
+            col: self.col.checked_add_signed(cols)?,
+        })
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {}, column {}", self.row, self.col)
+    }
+}
+
+/// Up, left, right and down.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// [`ORTHOGONAL`] and the diagonals, row by row.
+const ALL: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// The grid of `rows`, `None` when they are not all as long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        let cells = rows.into_iter().flatten().collect();
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parse one row per non-empty line of `input`, with `cell` turning each
+    /// character into a cell. Characters it rejects and rows of another width
+    /// than the first fail, with errors still to be located in the whole
+    /// input. No line is an empty grid.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> ParseResult<Self> {
+        let mut cells = vec![];
+        let (mut width, mut height) = (0, 0);
+        for line in input::lines(input) {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(ParseError::new(&line[i..i + c.len_utf8()], expected)),
+                }
+            }
+            let row = cells.len() - before;
+            if height == 0 {
+                width = row;
+            } else if row != width {
+                return Err(ParseError::new(line, format!("a row of {width} positions")));
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {col} outside of the grid");
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos { row, col }))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, for which `f` holds.
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    fn around(&self, pos: Pos, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .iter()
+            .filter_map(move |&(rows, cols)| pos.offset(rows, cols))
+            .filter(move |p| p.row < height && p.col < width)
+    }
+
+    /// The up to 4 positions above, left, right and below `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.around(pos, &ORTHOGONAL)
+    }
+
+    /// The up to 8 positions around `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.around(pos, &ALL)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |row, col| Pos::new(col, row))
+    }
+
+    /// The grid turned a quarter clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(height, self.width, |row, col| {
+            Pos::new(height - 1 - col, row)
+        })
+    }
+
+    /// The grid turned a quarter counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.height, width, |row, col| {
+            Pos::new(col, width - 1 - row)
+        })
+    }
+
+    /// A `width` by `height` grid taking every cell from the position `from`
+    /// gives for it.
+    fn rearranged(&self, width: usize, height: usize, from: impl Fn(usize, usize) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| self[from(row, col)].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos} outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{pos} outside of the grid");
+        &mut self.cells[pos.row * self.width + pos.col]
+    }
+}
+
+/// One line per row, the cells of a row written next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn parse_and_print() {
+    let grid = Grid::parse("\n ab.\n.cd\n", "a letter or `.`", |c| {
+        (c == '.' || c.is_ascii_lowercase()).then_some(c)
+    })
+    .unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Pos::new(1, 2)], 'd');
+    assert_eq!(grid.to_string(), "ab.\n.cd");
+    assert_eq!(grid.row(1), ['.', 'c', 'd']);
+    assert_eq!(grid.column(1).collect::<String>(), "bc");
+    assert_eq!(grid.position(|&c| c == 'c'), Some(Pos::new(1, 1)));
+
+    let e = Grid::parse("ab\nAb", "a letter", |c| {
+        c.is_ascii_lowercase().then_some(c)
+    })
+    .unwrap_err();
+    assert_eq!((e.token(), e.expected()), ("A", "a letter"));
+    let e = Grid::parse("ab\nabc", "a letter", Some).unwrap_err();
+    assert_eq!((e.token(), e.expected()), ("abc", "a row of 2 positions"));
+    assert!(Grid::parse(" \n", "a letter", Some).unwrap().is_empty());
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::new(3, 2, 0);
+    let corner: Vec<_> = grid.neighbours8(Pos::new(0, 0)).collect();
+    assert_eq!(corner, [Pos::new(0, 1), Pos::new(1, 0), Pos::new(1, 1)]);
+    assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+    let edge: Vec<_> = grid.neighbours4(Pos::new(1, 2)).collect();
+    assert_eq!(edge, [Pos::new(0, 2), Pos::new(1, 1)]);
+}
+
+#[test]
+fn rotate_and_transpose() {
+    let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    assert_eq!(grid.transpose().to_string(), "14\n25\n36");
+    assert_eq!(grid.rotate_right().to_string(), "41\n52\n63");
+    assert_eq!(grid.rotate_left().to_string(), "36\n25\n14");
+    assert_eq!(grid.rotate_right().rotate_left(), grid);
+    assert_eq!(grid.map(|n| n * 2).row(1), [8, 10, 12]);
+    assert!(Grid::from_rows(vec![vec![1], vec![2, 3]]).is_none());
+}
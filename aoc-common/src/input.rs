@@ -0,0 +1,176 @@
+//! Helpers for the input formats shared by most days.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A token of the input that does not have the expected shape.
+///
+/// Parsers create the error from the offending token, which must be a slice
+/// of the input, and [`ParseError::locate`] later turns the slice into a line
+/// and column of the whole input.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    token: String,
+    expected: String,
+    addr: usize,
+}
+
+pub type ParseResult<T> = ::std::result::Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(token: &str, expected: impl Into<String>) -> Self {
+        Self {
+            line: 0,
+            column: 0,
+            token: token.to_string(),
+            expected: expected.into(),
+            addr: token.as_ptr() as usize,
+        }
+    }
+
+    /// Fill in the position of the token inside `source`. Errors that are
+    /// already located, or whose token is not a slice of `source`, are kept
+    /// as they are.
+    pub fn locate(mut self, source: &str) -> Self {
+        let start = source.as_ptr() as usize;
+        if self.line != 0 || self.addr < start || self.addr > start + source.len() {
+            return self;
+        }
+        let before = &source[..self.addr - start];
+        self.line = before.matches('\n').count() + 1;
+        self.column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        self
+    }
+
+    /// 1 based line of the token, 0 when the error is not located.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1 based column of the token, 0 when the error is not located.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line != 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        if self.token.is_empty() {
+            write!(f, "expected {}, found nothing", self.expected)
+        } else {
+            write!(f, "expected {}, found {:?}", self.expected, self.token)
+        }
+    }
+}
+
+// `main` prints returned errors with `Debug`, keep it as readable as `Display`.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `token` with [`FromStr`], describing it as `expected` on failure.
+pub fn parse<T: FromStr>(token: &str, expected: &str) -> ParseResult<T> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(token, expected))
+}
+
+/// Non-empty lines of `input`, with surrounding whitespace trimmed.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(|l| l.trim()).filter(|l| !l.is_empty())
+}
+
+/// Blocks of `input` separated by one or more blank lines, each trimmed.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = input;
+    std::iter::from_fn(move || {
+        let start = rest.len() - rest.trim_start().len();
+        rest = &rest[start..];
+        if rest.is_empty() {
+            return None;
+        }
+        let mut end = rest.len();
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                end = offset;
+                break;
+            }
+            offset += line.len();
+        }
+        let block = rest[..end].trim_end();
+        rest = &rest[end..];
+        Some(block)
+    })
+}
+
+/// Parse a comma-separated list of numbers like `3,5,4,7`.
+pub fn comma_separated<T: FromStr>(s: &str) -> ParseResult<Vec<T>> {
+    s.split(',').map(|n| parse(n.trim(), "a number")).collect()
+}
+
+#[test]
+fn lines_test() {
+    let input = "  a \n\n b\n   \nc\n";
+    assert_eq!(lines(input).collect::<Vec<_>>(), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn blocks_test() {
+    let input = "\n3-5\n10-14\n\n \n1\n5\n\n";
+    assert_eq!(
+        blocks(input).collect::<Vec<_>>(),
+        vec!["3-5\n10-14", "1\n5"]
+    );
+    assert_eq!(blocks("one").collect::<Vec<_>>(), vec!["one"]);
+    assert_eq!(blocks("  \n").count(), 0);
+}
+
+#[test]
+fn comma_separated_test() -> ParseResult<()> {
+    assert_eq!(comma_separated::<usize>("3,5, 4,7")?, vec![3, 5, 4, 7]);
+    assert!(comma_separated::<usize>("3,x").is_err());
+    Ok(())
+}
+
+#[test]
+fn locate_error() {
+    let input = "1,2\n3,4\n5,x6\n";
+    let e = lines(input)
+        .map(comma_separated::<usize>)
+        .collect::<ParseResult<Vec<_>>>()
+        .unwrap_err()
+        .locate(input);
+    assert_eq!((e.line(), e.column(), e.token()), (3, 3, "x6"));
+    assert_eq!(
+        e.to_string(),
+        "line 3, column 3: expected a number, found \"x6\""
+    );
+
+    let e = ParseError::new(&input[input.len()..], "more input").locate(input);
+    assert_eq!((e.line(), e.column()), (4, 1));
+    let e = ParseError::new("elsewhere", "a slice of the input").locate(input);
+    assert_eq!(e.line(), 0);
+    assert_eq!(
+        e.to_string(),
+        "expected a slice of the input, found \"elsewhere\""
+    );
+}
@@ -0,0 +1,113 @@
+//! Shared pieces for every day of Advent of Code 2025.
+
+use std::error::Error;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+mod answer;
+pub mod bitgrid;
+pub mod build;
+pub mod cancel;
+pub mod fixtures;
+pub mod fuzz;
+pub mod grid;
+pub mod input;
+pub mod params;
+pub mod registry;
+mod solution;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use answer::Answer;
+pub use input::{ParseError, ParseResult};
+pub use solution::{Alternate, Assumption, Parsed, Part, Puzzle, Query, Solution, main, run};
+
+pub type Result<T> = ::std::result::Result<T, Box<dyn Error>>;
+
+/// Build an `Err` holding a formatted message, like `format!`.
+#[macro_export]
+macro_rules! err {
+    ($($tt:tt)*) => { Err(Box::<dyn ::std::error::Error>::from(format!($($tt)*))) }
+}
+
+/// Root of the cargo workspace holding every day.
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-common lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Directory holding the inputs of `day`: `$AOC_INPUT_DIR/aocNN` when the
+/// variable is set, `aocNN/input` in the workspace otherwise.
+pub fn input_dir(day: u8) -> PathBuf {
+    let name = format!("aoc{day:02}");
+    match std::env::var_os("AOC_INPUT_DIR") {
+        Some(dir) => PathBuf::from(dir).join(name),
+        None => workspace_root().join(name).join("input"),
+    }
+}
+
+/// Path of the personal puzzle input of `day`.
+pub fn input_path(day: u8) -> PathBuf {
+    input_dir(day).join("input.txt")
+}
+
+/// Read the personal puzzle input of `day`, see [`input_path`].
+pub fn read_input(day: u8) -> Result<String> {
+    read_path(input_path(day))
+}
+
+/// First bytes of a gzip stream.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Read an input from `path`, or from stdin when it is `-`. Gzip-compressed
+/// inputs are recognised by their first bytes, whatever the file is called.
+pub fn read_path(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let read = || -> std::io::Result<String> {
+        let mut bytes = vec![];
+        if path == Path::new("-") {
+            std::io::stdin().read_to_end(&mut bytes)?;
+        } else {
+            bytes = std::fs::read(path)?;
+        }
+        if bytes.starts_with(&GZIP_MAGIC) {
+            let mut decoded = vec![];
+            flate2::read::MultiGzDecoder::new(&bytes[..]).read_to_end(&mut decoded)?;
+            bytes = decoded;
+        }
+        String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    };
+    read().map_err(|e| format!("unable to read {}: {e}", path.display()).into())
+}
+
+/// Run `f` and measure how long it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let _start = Instant::now();
+    let value = f();
+    (value, _start.elapsed())
+}
+
+#[test]
+fn read_gzip() -> Result<()> {
+    use std::io::Write;
+
+    let dir = std::env::temp_dir();
+    let plain = dir.join(format!("aoc-plain-{}.txt", std::process::id()));
+    let gzip = dir.join(format!("aoc-gzip-{}", std::process::id()));
+    std::fs::write(&plain, "3-5\n\n1\n")?;
+    let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(b"3-5\n\n1\n")?;
+    std::fs::write(&gzip, encoder.finish()?)?;
+
+    let read = (read_path(&plain), read_path(&gzip));
+    std::fs::remove_file(&plain)?;
+    std::fs::remove_file(&gzip)?;
+    assert_eq!(read.0?, "3-5\n\n1\n");
+    assert_eq!(read.1?, "3-5\n\n1\n");
+    assert!(read_path(dir.join("aoc-missing")).is_err());
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//! Puzzle constants that can be changed at runtime.
+//!
+//! Every day declares its constants as [`Solution::Params`], a struct with the
+//! values of the puzzle as defaults. Other values are given by name, as
+//! `name=value` on the command line of the runner or in a config file holding
+//! one table per day:
+//!
+//! ```toml
+//! [aoc08]
+//! pairs = 10
+//! ```
+//!
+//! [`Solution::Params`]: crate::Solution::Params
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use toml::{Table, Value};
+
+use crate::{Result, err};
+
+/// Constants by name, replacing the defaults.
+pub type Overrides = Table;
+
+/// The defaults of `P` by name, none for `()`.
+pub fn defaults<P: Default + Serialize>() -> Table {
+    match Value::try_from(P::default()) {
+        Ok(Value::Table(table)) => table,
+        _ => Table::new(),
+    }
+}
+
+/// `P` with the defaults replaced by `overrides`, which may only name
+/// constants of `P`. Values given for text constants are taken as text.
+pub fn resolve<P: Default + Serialize + DeserializeOwned>(overrides: &Overrides) -> Result<P> {
+    if overrides.is_empty() {
+        return Ok(P::default());
+    }
+    let mut params = defaults::<P>();
+    for (name, value) in overrides {
+        let Some(default) = params.get_mut(name) else {
+            let known: Vec<&str> = params.keys().map(String::as_str).collect();
+            return match known[..] {
+                [] => err!("unknown parameter {name}, the day has none"),
+                _ => err!("unknown parameter {name}, expected {}", known.join(", ")),
+            };
+        };
+        *default = match value {
+            Value::String(_) => value.clone(),
+            _ if default.is_str() => Value::String(value.to_string()),
+            _ => value.clone(),
+        };
+    }
+    Value::Table(params).try_into().map_err(|e| {
+        let e = e.to_string();
+        format!("invalid parameters: {}", e.trim_end().replace('\n', " ")).into()
+    })
+}
+
+/// Parse `name=value`, with a TOML value or else plain text.
+pub fn assignment(s: &str) -> Result<(String, Value)> {
+    let Some((name, value)) = s.split_once('=') else {
+        return err!("expected name=value, got {s:?}");
+    };
+    let value = toml::from_str::<Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(value.to_string()));
+    Ok((name.trim().to_string(), value))
+}
+
+#[cfg(test)]
+#[derive(Debug, PartialEq, Serialize, serde::Deserialize)]
+struct Dial {
+    size: u32,
+    start: u32,
+    name: String,
+}
+
+#[cfg(test)]
+impl Default for Dial {
+    fn default() -> Self {
+        Self {
+            size: 100,
+            start: 50,
+            name: "safe".to_string(),
+        }
+    }
+}
+
+#[test]
+fn resolve_overrides() -> Result<()> {
+    assert_eq!(resolve::<Dial>(&Table::new())?, Dial::default());
+    let overrides: Table = [assignment("size=10")?, assignment("name = 42")?]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        resolve::<Dial>(&overrides)?,
+        Dial {
+            size: 10,
+            start: 50,
+            name: "42".to_string()
+        }
+    );
+
+    let unknown: Table = [assignment("stop=3")?].into_iter().collect();
+    assert_eq!(
+        resolve::<Dial>(&unknown).unwrap_err().to_string(),
+        "unknown parameter stop, expected name, size, start"
+    );
+    assert!(resolve::<()>(&unknown).is_err());
+    let invalid: Table = [assignment("size=big")?].into_iter().collect();
+    assert!(resolve::<Dial>(&invalid).is_err());
+    assert_eq!(defaults::<()>(), Table::new());
+    Ok(())
+}
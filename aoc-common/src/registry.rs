@@ -0,0 +1,218 @@
+//! Known answers of personal inputs, keyed by a hash of the input.
+//!
+//! Every engineer keeps their own `answers.toml` in the workspace root (or
+//! wherever `AOC_ANSWERS` points), so the same code can be checked against
+//! everyone's inputs:
+//!
+//! ```toml
+//! [aoc01.3f2b9c4d5e6a7b8c]
+//! part1 = "1100"
+//! part2 = "6358"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Part, Puzzle, Result, Solution, err, workspace_root};
+
+/// Stable hash of an input, trailing whitespace is ignored so a missing final
+/// newline does not change it.
+pub fn input_hash(input: &str) -> String {
+    // FNV-1a, 64 bits
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        });
+    format!("{hash:016x}")
+}
+
+/// Answers of both parts of one input.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct Entry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) part2: Option<String>,
+}
+
+impl Entry {
+    pub(crate) fn get(&self, part: Part) -> Option<&String> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, part: Part) -> &mut Option<String> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// How a computed answer compares with the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Match => write!(f, "match"),
+            Verdict::Mismatch { expected } => write!(f, "MISMATCH, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+pub struct Registry {
+    path: PathBuf,
+    days: BTreeMap<String, BTreeMap<String, Entry>>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("aoc{day:02}")
+}
+
+impl Registry {
+    /// `$AOC_ANSWERS`, or `answers.toml` in the workspace root.
+    pub fn default_path() -> PathBuf {
+        std::env::var_os("AOC_ANSWERS")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root().join("answers.toml"))
+    }
+
+    /// Load the registry at `path`, a missing file is an empty registry.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return err!("unable to read {}: {e}", path.display()),
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        std::fs::write(&self.path, toml::to_string(&self.days)?)?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, hash: &str, part: Part) -> Option<&str> {
+        self.days
+            .get(&day_key(day))?
+            .get(hash)?
+            .get(part)
+            .map(|s| s.as_str())
+    }
+
+    pub fn set(&mut self, day: u8, hash: &str, part: Part, answer: &Answer) {
+        *self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(hash.to_string())
+            .or_default()
+            .get_mut(part) = Some(answer.to_string());
+    }
+
+    pub fn check(&self, day: u8, hash: &str, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, hash, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer.to_string() => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// Solve `input` with every implementation of `S` and compare the answers
+/// with the registry. Parts without a registered answer are skipped, so the
+/// `real_input` tests pass for inputs nobody has recorded yet.
+pub fn verify<S: Solution + 'static>(input: &str) -> Result<()> {
+    let registry = Registry::load(Registry::default_path())?;
+    let hash = input_hash(input);
+    let parsed = Puzzle::of::<S>().parse(input)?;
+
+    let mut answers = vec![];
+    for part in Part::ALL {
+        answers.push((part, "main", parsed.solve(part)?));
+    }
+    for (part, name) in parsed.alternates(false) {
+        if let Some(answer) = parsed.solve_alternate(part, name) {
+            answers.push((part, name, answer?));
+        }
+    }
+
+    for (part, name, answer) in answers {
+        if answer == Answer::Unsolved {
+            continue;
+        }
+        match registry.check(S::DAY, &hash, part, &answer) {
+            Verdict::Match => {}
+            Verdict::Unknown => eprintln!(
+                "aoc{:02} part {part}: no registered answer for input {hash} in {}",
+                S::DAY,
+                registry.path().display()
+            ),
+            Verdict::Mismatch { expected } => {
+                return err!(
+                    "aoc{:02} part {part} ({name}): got {answer}, registered answer is {expected}",
+                    S::DAY
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn hash_ignores_trailing_whitespace() {
+    assert_eq!(input_hash("L68\nL30\n"), input_hash("L68\nL30"));
+    assert_ne!(input_hash("L68\nL30"), input_hash("L68\nL31"));
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+}
+
+#[test]
+fn registry_round_trip() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc-registry-{}.toml", std::process::id()));
+    let mut registry = Registry::load(&path)?;
+    assert_eq!(registry.get(1, "abc", Part::One), None);
+
+    registry.set(1, "abc", Part::One, &Answer::from(1100u32));
+    registry.save()?;
+
+    let registry = Registry::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(registry.get(1, "abc", Part::One), Some("1100"));
+    assert_eq!(registry.get(1, "abc", Part::Two), None);
+    assert_eq!(
+        registry.check(1, "abc", Part::One, &Answer::from(1100u32)),
+        Verdict::Match
+    );
+    assert_eq!(
+        registry.check(1, "abc", Part::One, &Answer::from(6358u32)),
+        Verdict::Mismatch {
+            expected: "1100".to_string()
+        }
+    );
+    assert_eq!(
+        registry.check(2, "abc", Part::One, &Answer::from(1u32)),
+        Verdict::Unknown
+    );
+    Ok(())
+}
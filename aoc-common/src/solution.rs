@@ -0,0 +1,401 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::fuzz::Rng;
+use crate::params::{self, Overrides};
+use crate::{Answer, Result, err, read_path, timed};
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(n: u8) -> std::result::Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("there is no part {n}")),
+        }
+    }
+}
+
+/// Another implementation of a part, kept next to the main one for comparison.
+pub struct Alternate<I> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: fn(&I) -> Result<Answer>,
+    /// Exploratory implementations too slow for real inputs, only run by the
+    /// cross-check and on fixtures.
+    pub slow: bool,
+}
+
+/// A property of the input that a solution relies on without checking it,
+/// e.g. a graph without cycles.
+pub struct Assumption<I> {
+    pub name: &'static str,
+    /// Where the input breaks the assumption, if it does.
+    pub check: fn(&I) -> std::result::Result<(), String>,
+}
+
+/// A question about a parsed input beyond the two parts, asked in the REPL
+/// of the runner, e.g. `paths you out`.
+pub struct Query<I> {
+    pub name: &'static str,
+    /// The arguments, e.g. `BOX after PAIRS`: upper case words stand for an
+    /// argument, other words are typed as they are. `run` is only called with
+    /// arguments that fit.
+    pub usage: &'static str,
+    pub help: &'static str,
+    /// The answer, or what is wrong with the arguments.
+    pub run: fn(&I, &[&str]) -> Result<String>,
+}
+
+/// Whether `args` fit the `usage` of a [`Query`].
+fn fits(usage: &str, args: &[&str]) -> bool {
+    let words: Vec<&str> = usage.split_whitespace().collect();
+    words.len() == args.len()
+        && words
+            .iter()
+            .zip(args)
+            .all(|(word, arg)| !word.chars().any(char::is_lowercase) || word == arg)
+}
+
+/// A day of the puzzle, solved without printing anything.
+pub trait Solution {
+    /// Day of the puzzle, starting from 1.
+    const DAY: u8;
+
+    /// Version of the main implementations, bumped whenever a change could
+    /// change an answer so answers cached by the runner are computed again.
+    const VERSION: u32 = 1;
+
+    type Input;
+
+    /// Puzzle constants that can be changed at runtime, with the values of
+    /// the puzzle as defaults, see [`crate::params`]. `()` when there are none.
+    type Params: Default + Serialize + DeserializeOwned;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse `input` for other constants than the defaults. Days with
+    /// [`Solution::Params`] keep them in their input.
+    fn parse_with(input: &str, _params: &Self::Params) -> Result<Self::Input> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer>;
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(Answer::Unsolved)
+    }
+
+    /// Other implementations of the parts, e.g. a brute force and a smarter one.
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        Vec::new()
+    }
+
+    /// Assumptions about the input, checked by the `validate` command of the
+    /// runner before a wrong answer is produced.
+    fn assumptions() -> Vec<Assumption<Self::Input>> {
+        Vec::new()
+    }
+
+    /// Questions about a parsed input, to explore it while debugging.
+    fn queries() -> Vec<Query<Self::Input>> {
+        Vec::new()
+    }
+
+    /// Split an input into smaller inputs that are solved independently, e.g.
+    /// one per line, so a disagreement between implementations can be narrowed
+    /// down to the first diverging case. Empty when the input is one piece.
+    fn cases(_input: &str) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// A small random input for differential testing of the alternates, see
+    /// [`crate::fuzz`].
+    fn generate(_rng: &mut Rng) -> Option<String> {
+        None
+    }
+}
+
+/// A parsed input, with the day it belongs to erased.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<Answer>;
+
+    /// Part and name of every alternate implementation, including the slow
+    /// ones only when asked for.
+    fn alternates(&self, slow: bool) -> Vec<(Part, &'static str)>;
+
+    /// Solve with the alternate implementation of `part` called `name`.
+    fn solve_alternate(&self, part: Part, name: &str) -> Option<Result<Answer>>;
+
+    /// Check every assumption about the input, by name.
+    fn validate(&self) -> Vec<(&'static str, std::result::Result<(), String>)>;
+
+    /// Name, usage and help of every query, see [`Solution::queries`].
+    fn queries(&self) -> Vec<(&'static str, &'static str, &'static str)>;
+
+    /// Answer the query called `name`, `None` when there is none.
+    fn query(&self, name: &str, args: &[&str]) -> Option<Result<String>>;
+}
+
+struct Input<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Input<S> {
+    fn solve(&self, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => S::part1(&self.0),
+            Part::Two => S::part2(&self.0),
+        }
+    }
+
+    fn alternates(&self, slow: bool) -> Vec<(Part, &'static str)> {
+        S::alternates()
+            .iter()
+            .filter(|a| slow || !a.slow)
+            .map(|a| (a.part, a.name))
+            .collect()
+    }
+
+    fn solve_alternate(&self, part: Part, name: &str) -> Option<Result<Answer>> {
+        S::alternates()
+            .iter()
+            .find(|a| a.part == part && a.name == name)
+            .map(|a| (a.solve)(&self.0))
+    }
+
+    fn validate(&self) -> Vec<(&'static str, std::result::Result<(), String>)> {
+        S::assumptions()
+            .iter()
+            .map(|a| (a.name, (a.check)(&self.0)))
+            .collect()
+    }
+
+    fn queries(&self) -> Vec<(&'static str, &'static str, &'static str)> {
+        S::queries()
+            .iter()
+            .map(|q| (q.name, q.usage, q.help))
+            .collect()
+    }
+
+    fn query(&self, name: &str, args: &[&str]) -> Option<Result<String>> {
+        S::queries().iter().find(|q| q.name == name).map(|q| {
+            if !fits(q.usage, args) {
+                return err!("usage: {name} {}", q.usage);
+            }
+            (q.run)(&self.0, args)
+        })
+    }
+}
+
+/// A [`Solution`] behind function pointers, so days can be kept in one table.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    day: u8,
+    version: u32,
+    parse: fn(&str) -> Result<Box<dyn Parsed>>,
+    parse_with: fn(&str, &Overrides) -> Result<Box<dyn Parsed>>,
+    params: fn() -> Overrides,
+    cases: fn(&str) -> Vec<&str>,
+    generate: fn(&mut Rng) -> Option<String>,
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>> {
+    Ok(Box::new(Input::<S>(S::parse(input)?)))
+}
+
+fn parse_with_boxed<S: Solution + 'static>(
+    input: &str,
+    overrides: &Overrides,
+) -> Result<Box<dyn Parsed>> {
+    let params = params::resolve::<S::Params>(overrides)?;
+    Ok(Box::new(Input::<S>(S::parse_with(input, &params)?)))
+}
+
+impl Puzzle {
+    pub const fn of<S: Solution + 'static>() -> Self {
+        Self {
+            day: S::DAY,
+            version: S::VERSION,
+            parse: parse_boxed::<S>,
+            parse_with: parse_with_boxed::<S>,
+            params: params::defaults::<S::Params>,
+            cases: S::cases,
+            generate: S::generate,
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// See [`Solution::VERSION`].
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Parsed>> {
+        (self.parse)(input)
+    }
+
+    /// Parse `input` with some constants other than the defaults, see
+    /// [`crate::params`].
+    pub fn parse_with(&self, input: &str, overrides: &Overrides) -> Result<Box<dyn Parsed>> {
+        (self.parse_with)(input, overrides)
+    }
+
+    /// The constants of the puzzle with their defaults.
+    pub fn params(&self) -> Overrides {
+        (self.params)()
+    }
+
+    /// See [`Solution::cases`].
+    pub fn cases<'a>(&self, input: &'a str) -> Vec<&'a str> {
+        (self.cases)(input)
+    }
+
+    /// See [`Solution::generate`].
+    pub fn generate(&self, rng: &mut Rng) -> Option<String> {
+        (self.generate)(rng)
+    }
+
+    /// Solve `input` and print the answers with the time each part took.
+    /// `None` runs both parts, alternates are only run when asked for.
+    pub fn run(&self, input: &str, part: Option<Part>, alternates: bool) -> Result<()> {
+        let parsed = self.parse(input)?;
+
+        for p in Part::ALL {
+            if part.is_some_and(|part| part != p) {
+                continue;
+            }
+            let (answer, elapsed) = timed(|| parsed.solve(p));
+            let answer = answer?;
+            if answer == Answer::Unsolved {
+                continue;
+            }
+            println!("part {p}: {answer}");
+            println!("> Time elapsed is: {elapsed:?}");
+
+            if alternates {
+                for (_, name) in parsed
+                    .alternates(false)
+                    .into_iter()
+                    .filter(|(ap, _)| ap == &p)
+                {
+                    let (answer, elapsed) = timed(|| parsed.solve_alternate(p, name));
+                    if let Some(answer) = answer {
+                        println!("part {p} ({name}): {}", answer?);
+                        println!("> Time elapsed is: {elapsed:?}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Entry point of every day's binary: solve `input` and print all answers,
+/// including the alternate implementations.
+pub fn run<S: Solution + 'static>(input: &str) -> Result<()> {
+    Puzzle::of::<S>().run(input, None, true)
+}
+
+/// Paths given as `--input PATH` or `--input=PATH`, any number of times,
+/// stdin `-` when there is none.
+fn input_args(args: impl IntoIterator<Item = String>) -> Result<Vec<PathBuf>> {
+    let mut args = args.into_iter();
+    let mut paths = vec![];
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--input") {
+            Some("") => match args.next() {
+                Some(path) => paths.push(path.into()),
+                None => return err!("`--input` needs a path, `-` for stdin"),
+            },
+            Some(path) if path.starts_with('=') => paths.push(path[1..].into()),
+            _ => return err!("unexpected argument {arg:?}, usage: [--input PATH]..."),
+        }
+    }
+    if paths.is_empty() {
+        paths.push("-".into());
+    }
+    Ok(paths)
+}
+
+/// `main` of every day's binary: [`run`] on each input given with
+/// `--input`, read with [`read_path`], or on stdin. With several inputs the
+/// answers are printed under the path of each and the inputs that fail do not
+/// stop the others.
+pub fn main<S: Solution + 'static>() -> Result<()> {
+    let paths = input_args(std::env::args().skip(1))?;
+    if let [path] = &paths[..] {
+        return run::<S>(&read_path(path)?);
+    }
+    let mut failed = 0;
+    for path in &paths {
+        println!("== {}", path.display());
+        if let Err(e) = read_path(path).and_then(|input| run::<S>(&input)) {
+            eprintln!("{}: {e}", path.display());
+            failed += 1;
+        }
+    }
+    if failed > 0 {
+        return err!("{failed} of {} inputs failed", paths.len());
+    }
+    Ok(())
+}
+
+#[test]
+fn query_usage() {
+    assert!(fits("", &[]));
+    assert!(fits("BOX after PAIRS", &["17", "after", "500"]));
+    assert!(!fits("BOX after PAIRS", &["17", "before", "500"]));
+    assert!(!fits("FROM TO", &["you"]));
+}
+
+#[test]
+fn parse_input_args() -> Result<()> {
+    let args = |args: &[&str]| input_args(args.iter().map(|a| a.to_string()));
+    assert_eq!(args(&[])?, [PathBuf::from("-")]);
+    assert_eq!(
+        args(&["--input", "a.txt", "--input=b.txt.gz", "--input", "-"])?,
+        [
+            PathBuf::from("a.txt"),
+            PathBuf::from("b.txt.gz"),
+            PathBuf::from("-")
+        ]
+    );
+    assert!(args(&["--input"]).is_err());
+    assert!(args(&["a.txt"]).is_err());
+    Ok(())
+}
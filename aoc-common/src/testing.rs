@@ -0,0 +1,45 @@
+//! Toy days for the tests of the runner and of the helpers here, with the
+//! `testing` feature.
+
+use crate::fuzz::Rng;
+use crate::{Alternate, Answer, Part, Result, Solution};
+
+/// Sums comma-separated numbers, with an alternate that wrongly sums them
+/// modulo 100, so it disagrees on any number from 100 on.
+pub struct Sum;
+
+impl Solution for Sum {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(crate::input::comma_separated(input)?)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer> {
+        Ok(numbers.iter().sum::<u32>().into())
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![Alternate {
+            part: Part::One,
+            name: "wrapping",
+            solve: |numbers| Ok(numbers.iter().map(|n| n % 100).sum::<u32>().into()),
+            slow: false,
+        }]
+    }
+
+    fn cases(input: &str) -> Vec<&str> {
+        input.split(',').collect()
+    }
+
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let numbers: Vec<String> = (0..rng.index(1..=8))
+            .map(|_| rng.range(0..=1000).to_string())
+            .collect();
+        Some(numbers.join(","))
+    }
+}
@@ -0,0 +1,137 @@
+//! Opt-in accounting of heap allocations.
+//!
+//! With the `alloc-stats` feature of the runner a global allocator wrapping
+//! the system one counts allocations, allocated bytes and the peak of live
+//! bytes. Without it [`measure`] reports nothing and costs nothing. The
+//! counters are global, so only measure one thing at a time. A phase given up
+//! after a timeout may still be allocating on its thread, so nothing is
+//! measured any more once one is, see [`abandoned`].
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
+
+use serde::Serialize;
+
+/// Whether a phase was left running, see [`abandoned`].
+static ABANDONED: AtomicBool = AtomicBool::new(false);
+
+/// Tell that a phase was left running after a timeout. Its allocations would
+/// be counted in whatever is measured next, so [`measure`] reports nothing
+/// from now on.
+pub fn abandoned() {
+    ABANDONED.store(true, Relaxed);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    /// Allocations, a reallocation counts as one.
+    pub allocations: u64,
+    /// Bytes allocated, a reallocation counts its new size.
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live before.
+    pub peak_bytes: u64,
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::AtomicU64;
+    use std::sync::atomic::Ordering::Relaxed;
+
+    pub static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    pub static BYTES: AtomicU64 = AtomicU64::new(0);
+    pub static LIVE: AtomicU64 = AtomicU64::new(0);
+    pub static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size as u64, Relaxed);
+        let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+        PEAK.fetch_max(live, Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size as u64, Relaxed);
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+}
+
+/// Run `f` and count what it allocated, `None` without the `alloc-stats`
+/// feature or once a phase was [`abandoned`].
+#[cfg(feature = "alloc-stats")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    use counting::{ALLOCATIONS, BYTES, LIVE, PEAK};
+
+    let live = LIVE.load(Relaxed);
+    PEAK.store(live, Relaxed);
+    let (allocations, bytes) = (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed));
+    let value = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (value, Some(stats).filter(|_| !ABANDONED.load(Relaxed)))
+}
+
+/// Run `f` and count what it allocated, `None` without the `alloc-stats`
+/// feature or once a phase was [`abandoned`].
+#[cfg(not(feature = "alloc-stats"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    (f(), None)
+}
+
+#[cfg(feature = "alloc-stats")]
+#[test]
+fn count_allocations() {
+    let (sum, stats) = measure(|| {
+        let mut v: Vec<u64> = Vec::with_capacity(100);
+        v.extend(0..100);
+        let w = vec![0u8; 4000];
+        drop(w);
+        let x = vec![1u8; 1000];
+        v.iter().sum::<u64>() + x.len() as u64
+    });
+    assert_eq!(sum, 5950);
+    // other tests allocate concurrently
+    let stats = stats.unwrap();
+    assert!(stats.allocations >= 3);
+    assert!(stats.bytes >= 800 + 4000 + 1000);
+    assert!(stats.peak_bytes >= 800 + 4000);
+}
@@ -0,0 +1,297 @@
+//! Running one day on every input of a directory, e.g. the personal inputs
+//! of the whole team, to catch solutions that only work on some of them.
+//!
+//! Every file of the directory is an input, read with
+//! [`aoc_common::read_path`] so it may be gzip-compressed, except the sidecar
+//! answers: `alice.answers.toml` next to `alice.txt` (or `alice.txt.gz`) holds
+//! the expected answers of that input:
+//!
+//! ```toml
+//! part1 = "1100"
+//! part2 = "6358"
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use aoc_common::params::Overrides;
+use aoc_common::{Answer, Part, Puzzle, read_path};
+use serde::Deserialize;
+
+use crate::Result;
+use crate::phases::{self, Outcome};
+
+/// Suffix of the sidecar answers of an input.
+const SIDECAR: &str = ".answers.toml";
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Expected {
+    fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// Every answer in the sidecar matches.
+    Pass,
+    Fail,
+    /// No sidecar, or no answer in it.
+    Unchecked,
+    Error(String),
+}
+
+#[derive(Debug)]
+pub struct InputResult {
+    /// File name of the input.
+    pub name: String,
+    /// What each solved part gave, an answer or why there is none, and how
+    /// long it took.
+    pub parts: Vec<(Part, String, Duration)>,
+    pub status: Status,
+}
+
+/// The sidecar answers of the input at `path`, its file name up to the first
+/// `.` followed by [`SIDECAR`].
+fn sidecar(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let stem = name.split('.').next().unwrap_or_default();
+    path.with_file_name(format!("{stem}{SIDECAR}"))
+}
+
+/// Input files of `dir` by name, hidden files and sidecars left out.
+fn inputs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("unable to read {}: {e}", dir.display()))?;
+    let mut paths = vec![];
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.file_type()?.is_file() && !name.starts_with('.') && !name.ends_with(SIDECAR) {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn expected(path: &Path) -> Result<Option<Expected>> {
+    let path = sidecar(path);
+    match std::fs::read_to_string(&path) {
+        Ok(s) => Ok(Some(
+            toml::from_str(&s).map_err(|e| format!("{}: {e}", path.display()))?,
+        )),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("unable to read {}: {e}", path.display()).into()),
+    }
+}
+
+/// Solve both parts of the input at `path` and compare them with its
+/// sidecar.
+fn run_input(
+    puzzle: &Puzzle,
+    path: &Path,
+    overrides: &Overrides,
+    timeout: Option<Duration>,
+) -> InputResult {
+    let name = path
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned();
+    let error = |e: String| InputResult {
+        name: name.clone(),
+        parts: vec![],
+        status: Status::Error(e),
+    };
+    let (input, expected) = match (read_path(path), expected(path)) {
+        (Ok(input), Ok(expected)) => (input, expected.unwrap_or_default()),
+        (Err(e), _) | (_, Err(e)) => return error(e.to_string()),
+    };
+
+    let mut parts = vec![];
+    let (mut checked, mut failed, mut errors) = (false, false, vec![]);
+    for phase in phases::solve(puzzle, &input, overrides, None, false, timeout) {
+        if let Err(e) = phase.parse_error() {
+            return error(e.to_string());
+        }
+        let Some(part) = phase.part else {
+            continue;
+        };
+        let shown = match phase.outcome {
+            Outcome::Solved(Answer::Unsolved) | Outcome::Parsed => continue,
+            Outcome::Solved(answer) => {
+                let answer = answer.to_string();
+                match expected.get(part) {
+                    Some(expected) if expected != answer => {
+                        failed = true;
+                        format!("{answer} (expected {expected})")
+                    }
+                    Some(_) => {
+                        checked = true;
+                        answer
+                    }
+                    None => answer,
+                }
+            }
+            Outcome::Cached(_) => unreachable!("batch runs do not use the cache"),
+            Outcome::Failed(e) => {
+                errors.push(format!("part {part}: {e}"));
+                "failed".to_string()
+            }
+            Outcome::TimedOut => {
+                errors.push(format!("part {part} timed out"));
+                "timed out".to_string()
+            }
+        };
+        parts.push((part, shown, phase.elapsed));
+    }
+
+    let status = if !errors.is_empty() {
+        Status::Error(errors.join(", "))
+    } else if failed {
+        Status::Fail
+    } else if checked {
+        Status::Pass
+    } else {
+        Status::Unchecked
+    };
+    InputResult {
+        name,
+        parts,
+        status,
+    }
+}
+
+/// Run `puzzle` on every input of `dir`, `jobs` inputs at a time, in the
+/// order of their names.
+pub fn batch(
+    puzzle: &Puzzle,
+    dir: &Path,
+    overrides: &Overrides,
+    timeout: Option<Duration>,
+    jobs: usize,
+) -> Result<Vec<InputResult>> {
+    let paths = inputs(dir)?;
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(paths.len()));
+    std::thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, paths.len().max(1)) {
+            s.spawn(|| {
+                while let Some(path) = paths.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = run_input(puzzle, path, overrides, timeout);
+                    results.lock().unwrap().push(result);
+                }
+            });
+        }
+    });
+    let mut results = results.into_inner().unwrap();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(results)
+}
+
+/// Print one row per input with the answers, timings and status.
+pub fn print(results: &[InputResult]) {
+    let column = |part: Part| {
+        results
+            .iter()
+            .flat_map(|r| &r.parts)
+            .filter(|(p, _, _)| *p == part)
+            .map(|(_, shown, _)| shown.len())
+            .max()
+            .unwrap_or(0)
+            .max(6)
+    };
+    let name = results
+        .iter()
+        .map(|r| r.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let widths = [column(Part::One), column(Part::Two)];
+
+    print!("{:<name$}", "input");
+    for (part, width) in Part::ALL.into_iter().zip(widths) {
+        print!("  {:<width$} {:>10}", format!("part {part}"), "time");
+    }
+    println!("  result");
+    for result in results {
+        print!("{:<name$}", result.name);
+        for (part, width) in Part::ALL.into_iter().zip(widths) {
+            match result.parts.iter().find(|(p, _, _)| *p == part) {
+                Some((_, shown, elapsed)) => {
+                    print!("  {shown:<width$} {:>10}", format!("{elapsed:.2?}"))
+                }
+                None => print!("  {:<width$} {:>10}", "-", "-"),
+            }
+        }
+        match &result.status {
+            Status::Pass => println!("  pass"),
+            Status::Fail => println!("  FAIL"),
+            Status::Unchecked => println!("  unchecked"),
+            Status::Error(e) => println!("  ERROR {e}"),
+        }
+    }
+}
+
+#[test]
+fn batch_inputs() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let input = "L68\nL30\nR48\n";
+    std::fs::write(dir.join("alice.txt"), input)?;
+    std::fs::write(
+        dir.join("alice.answers.toml"),
+        "part1 = \"1\"\npart2 = \"2\"\n",
+    )?;
+    std::fs::write(dir.join("bob.txt"), input)?;
+    std::fs::write(dir.join("bob.answers.toml"), "part1 = \"3\"\n")?;
+    std::fs::write(dir.join("carol.txt"), input)?;
+    std::fs::write(dir.join("dave.txt"), "L68\nX30\n")?;
+
+    let puzzle = crate::days::find(1).unwrap();
+    let results = batch(puzzle, &dir, &Overrides::new(), None, 3);
+    std::fs::remove_dir_all(&dir)?;
+    let results: Vec<_> = results?
+        .into_iter()
+        .map(|r| {
+            let parts: Vec<_> = r.parts.into_iter().map(|(_, shown, _)| shown).collect();
+            (r.name, parts, r.status)
+        })
+        .collect();
+    assert_eq!(
+        results[..3],
+        [
+            (
+                "alice.txt".to_string(),
+                vec!["1".to_string(), "2".to_string()],
+                Status::Pass
+            ),
+            (
+                "bob.txt".to_string(),
+                vec!["1 (expected 3)".to_string(), "2".to_string()],
+                Status::Fail
+            ),
+            (
+                "carol.txt".to_string(),
+                vec!["1".to_string(), "2".to_string()],
+                Status::Unchecked
+            ),
+        ]
+    );
+    assert!(
+        matches!(&results[3], (name, parts, Status::Error(_)) if name == "dave.txt" && parts.is_empty())
+    );
+    Ok(())
+}
@@ -0,0 +1,218 @@
+//! Repeated timings of each phase of a day, compared with the previous run.
+//!
+//! Every phase (parsing and each solved part) is warmed up, then timed a
+//! number of times and summarised by its min, median and 95th percentile.
+//! Runs are kept in `bench.toml` in the workspace root (or wherever
+//! `AOC_BENCH` points), and a phase is flagged when its median regressed
+//! beyond a threshold compared to the previous run on the same input:
+//!
+//! ```toml
+//! [[aoc04]]
+//! at = 1764565200
+//! git_rev = "c704c0d"
+//! input_hash = "cbf29ce484222325"
+//! runs = 20
+//!
+//! [aoc04.phases.part1]
+//! min_ns = 81234
+//! median_ns = 83310
+//! p95_ns = 90112
+//! ```
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::registry::input_hash;
+use aoc_common::{Answer, Part, Puzzle, err, timed, workspace_root};
+use serde::{Deserialize, Serialize};
+
+use crate::{DayName, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    /// Summarise samples, of which there must be at least one.
+    pub fn of(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+        // nearest rank
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        let ns = |d: Duration| d.as_nanos().try_into().unwrap_or(u64::MAX);
+        Self {
+            min_ns: ns(samples[0]),
+            median_ns: ns(rank(50)),
+            p95_ns: ns(rank(95)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Run {
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_rev: Option<String>,
+    pub input_hash: String,
+    pub runs: usize,
+    /// `parse`, `part1` and `part2`, unsolved parts are left out.
+    pub phases: BTreeMap<String, Stats>,
+}
+
+fn time<T>(runs: usize, warmup: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..warmup {
+        black_box(f()?);
+    }
+    let mut samples = vec![];
+    for _ in 0..runs {
+        let (result, elapsed) = timed(&mut f);
+        black_box(result?);
+        samples.push(elapsed);
+    }
+    Ok(Stats::of(&mut samples))
+}
+
+/// Time every phase of `puzzle` on `input` `runs` times, after `warmup` runs
+/// that are not counted.
+pub fn bench(puzzle: &Puzzle, input: &str, runs: usize, warmup: usize) -> Result<Run> {
+    if runs == 0 {
+        return err!("at least one run is needed");
+    }
+    let mut phases = BTreeMap::new();
+    phases.insert(
+        "parse".to_string(),
+        time(runs, warmup, || puzzle.parse(input))?,
+    );
+    let parsed = puzzle.parse(input)?;
+    for part in Part::ALL {
+        if parsed.solve(part)? == Answer::Unsolved {
+            continue;
+        }
+        let stats = time(runs, warmup, || parsed.solve(part))?;
+        phases.insert(format!("part{part}"), stats);
+    }
+    Ok(Run {
+        at: crate::submit::now(),
+        git_rev: crate::report::git_rev(),
+        input_hash: input_hash(input),
+        runs,
+        phases,
+    })
+}
+
+/// Phases of `run` whose median is more than `threshold` percent above the
+/// one of `previous`, with the relative change.
+pub fn regressions(previous: &Run, run: &Run, threshold: f64) -> Vec<(String, f64)> {
+    run.phases
+        .iter()
+        .filter_map(|(phase, stats)| {
+            let before = previous.phases.get(phase)?.median_ns;
+            let change = change(before, stats.median_ns)?;
+            (change > threshold).then(|| (phase.clone(), change))
+        })
+        .collect()
+}
+
+/// Relative change in percent, `None` when there was nothing to compare to.
+pub fn change(before: u64, after: u64) -> Option<f64> {
+    (before > 0).then(|| (after as f64 / before as f64 - 1.0) * 100.0)
+}
+
+pub struct History {
+    path: PathBuf,
+    days: BTreeMap<String, Vec<Run>>,
+}
+
+impl History {
+    /// `$AOC_BENCH`, or `bench.toml` in the workspace root.
+    pub fn default_path() -> PathBuf {
+        std::env::var_os("AOC_BENCH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root().join("bench.toml"))
+    }
+
+    /// Load the history at `path`, a missing file is an empty history.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return err!("unable to read {}: {e}", path.display()),
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        std::fs::write(&self.path, toml::to_string(&self.days)?)?;
+        Ok(())
+    }
+
+    /// The last run of `day` on the input with hash `input_hash`, runs on
+    /// other inputs cannot be compared.
+    pub fn previous(&self, day: u8, input_hash: &str) -> Option<&Run> {
+        self.days
+            .get(&DayName(day).to_string())?
+            .iter()
+            .rev()
+            .find(|run| run.input_hash == input_hash)
+    }
+
+    pub fn record(&mut self, day: u8, run: Run) {
+        self.days
+            .entry(DayName(day).to_string())
+            .or_default()
+            .push(run);
+    }
+}
+
+#[test]
+fn percentiles() {
+    let mut samples: Vec<Duration> = (1..=20).rev().map(Duration::from_nanos).collect();
+    let stats = Stats::of(&mut samples);
+    assert_eq!((stats.min_ns, stats.median_ns, stats.p95_ns), (1, 10, 19));
+    let stats = Stats::of(&mut [Duration::from_nanos(7)]);
+    assert_eq!((stats.min_ns, stats.median_ns, stats.p95_ns), (7, 7, 7));
+}
+
+#[test]
+fn flag_regressions() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc-bench-{}.toml", std::process::id()));
+    let mut history = History::load(&path)?;
+    let puzzle = crate::days::find(1).unwrap();
+    let run = bench(puzzle, "L68\nL30\nR48\n", 5, 1)?;
+    assert_eq!(
+        run.phases.keys().collect::<Vec<_>>(),
+        ["parse", "part1", "part2"]
+    );
+    history.record(1, run.clone());
+    history.save()?;
+    let history = History::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert!(history.previous(1, "0000000000000000").is_none());
+    let previous = history.previous(1, &run.input_hash).unwrap();
+    assert_eq!(previous.phases, run.phases);
+
+    let mut slower = run.clone();
+    let stats = |median_ns| Stats {
+        min_ns: median_ns,
+        median_ns,
+        p95_ns: median_ns,
+    };
+    let mut baseline = run;
+    baseline.phases.insert("part1".to_string(), stats(1000));
+    baseline.phases.insert("part2".to_string(), stats(1000));
+    slower.phases.insert("part1".to_string(), stats(1050));
+    slower.phases.insert("part2".to_string(), stats(1200));
+    slower.phases.remove("parse");
+    let flagged = regressions(&baseline, &slower, 10.0);
+    assert_eq!(flagged.len(), 1);
+    assert_eq!(flagged[0].0, "part2");
+    assert!((flagged[0].1 - 20.0).abs() < 1e-9);
+    Ok(())
+}
@@ -0,0 +1,274 @@
+//! Answers of parts already solved, so expensive parts are not solved again.
+//!
+//! Answers are kept in `cache.toml` in the workspace root (or wherever
+//! `AOC_CACHE` points) by day, hash of the input and part, together with the
+//! [`Solution::VERSION`] of the day they were solved with and how long that
+//! took. An answer of another version is solved again, parts run with
+//! parameters other than the defaults are never cached. A part without a
+//! puzzle, see [`Answer::Unsolved`], is cached without an answer so the
+//! input is not parsed again just to find that out:
+//!
+//! ```toml
+//! [aoc09.3f2b9c4d5e6a7b8c.part2]
+//! version = 1
+//! answer = "1476550548"
+//! duration_ns = 153092114
+//!
+//! [aoc12.8d1e0a7c6b5f4e3d.part2]
+//! version = 2
+//! duration_ns = 1042
+//! ```
+//!
+//! [`Solution::VERSION`]: aoc_common::Solution::VERSION
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_common::params::Overrides;
+use aoc_common::registry::input_hash;
+use aoc_common::{Answer, Part, Puzzle, err, workspace_root};
+use serde::{Deserialize, Serialize};
+
+use crate::phases::{self, Outcome, Phase};
+use crate::{DayName, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cached {
+    pub version: u32,
+    /// `None` for a part without a puzzle.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    pub duration_ns: u64,
+}
+
+/// Cached parts by day, input hash and part.
+type Days = BTreeMap<String, BTreeMap<String, BTreeMap<String, Cached>>>;
+
+pub struct Cache {
+    path: PathBuf,
+    days: Days,
+}
+
+fn part_key(part: Part) -> String {
+    format!("part{part}")
+}
+
+impl Cache {
+    /// `$AOC_CACHE`, or `cache.toml` in the workspace root.
+    pub fn default_path() -> PathBuf {
+        std::env::var_os("AOC_CACHE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root().join("cache.toml"))
+    }
+
+    /// Load the cache at `path`, a missing file is an empty cache.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return err!("unable to read {}: {e}", path.display()),
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        std::fs::write(&self.path, toml::to_string(&self.days)?)?;
+        Ok(())
+    }
+
+    /// The answer of `part` of `puzzle` for the input with hash `hash`,
+    /// unless it was solved by another version.
+    pub fn get(&self, puzzle: &Puzzle, hash: &str, part: Part) -> Option<&Cached> {
+        self.days
+            .get(&DayName(puzzle.day()).to_string())?
+            .get(hash)?
+            .get(&part_key(part))
+            .filter(|cached| cached.version == puzzle.version())
+    }
+
+    pub fn insert(
+        &mut self,
+        puzzle: &Puzzle,
+        hash: &str,
+        part: Part,
+        answer: &Answer,
+        elapsed: Duration,
+    ) {
+        let cached = Cached {
+            version: puzzle.version(),
+            answer: (*answer != Answer::Unsolved).then(|| answer.to_string()),
+            duration_ns: elapsed.as_nanos() as u64,
+        };
+        self.days
+            .entry(DayName(puzzle.day()).to_string())
+            .or_default()
+            .entry(hash.to_string())
+            .or_default()
+            .insert(part_key(part), cached);
+    }
+}
+
+/// [`phases::solve`] without alternates, taking the parts that are in `cache`
+/// from it and adding the parts that get solved. Cached parts are reported as
+/// [`Outcome::Cached`] with the time they took when they were solved, the
+/// input is not even parsed when every part is cached.
+pub fn solve(
+    cache: &mut Cache,
+    puzzle: &Puzzle,
+    input: &str,
+    overrides: &Overrides,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+) -> Vec<Phase> {
+    if !overrides.is_empty() {
+        return phases::solve(puzzle, input, overrides, part, false, timeout);
+    }
+    let hash = input_hash(input);
+    let mut cached = vec![];
+    let mut unsolved = vec![];
+    for p in Part::ALL {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+        match cache.get(puzzle, &hash, p) {
+            Some(hit) => cached.push(Phase {
+                part: Some(p),
+                name: "main",
+                outcome: match &hit.answer {
+                    Some(answer) => Outcome::Cached(answer.clone()),
+                    None => Outcome::Solved(Answer::Unsolved),
+                },
+                elapsed: Duration::from_nanos(hit.duration_ns),
+                alloc: None,
+            }),
+            None => unsolved.push(p),
+        }
+    }
+
+    let mut solved = match unsolved[..] {
+        [] => vec![],
+        [p] => phases::solve(puzzle, input, overrides, Some(p), false, timeout),
+        _ => phases::solve(puzzle, input, overrides, part, false, timeout),
+    };
+    for phase in &solved {
+        if let (Some(p), Outcome::Solved(answer)) = (phase.part, &phase.outcome) {
+            cache.insert(puzzle, &hash, p, answer, phase.elapsed);
+        }
+    }
+    solved.extend(cached);
+    solved.sort_by_key(|phase| phase.part);
+    solved
+}
+
+#[test]
+fn reuse_cached_parts() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc-cache-{}.toml", std::process::id()));
+    let mut cache = Cache::load(&path)?;
+    let puzzle = crate::days::find(1).unwrap();
+    let input = "L68\nL30\nR48\n";
+    let outcomes = |phases: Vec<Phase>| -> Vec<_> {
+        phases
+            .into_iter()
+            .map(|phase| (phase.part, phase.outcome))
+            .collect()
+    };
+
+    let solved = solve(
+        &mut cache,
+        puzzle,
+        input,
+        &Overrides::new(),
+        Some(Part::Two),
+        None,
+    );
+    assert_eq!(
+        outcomes(solved),
+        [
+            (None, Outcome::Parsed),
+            (Some(Part::Two), Outcome::Solved(2u32.into()))
+        ]
+    );
+    cache.save()?;
+    let mut cache = Cache::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(
+        outcomes(solve(
+            &mut cache,
+            puzzle,
+            input,
+            &Overrides::new(),
+            None,
+            None
+        )),
+        [
+            (None, Outcome::Parsed),
+            (Some(Part::One), Outcome::Solved(1u32.into())),
+            (Some(Part::Two), Outcome::Cached("2".to_string())),
+        ]
+    );
+    assert_eq!(
+        outcomes(solve(
+            &mut cache,
+            puzzle,
+            input,
+            &Overrides::new(),
+            None,
+            None
+        )),
+        [
+            (Some(Part::One), Outcome::Cached("1".to_string())),
+            (Some(Part::Two), Outcome::Cached("2".to_string())),
+        ]
+    );
+
+    // another version solves again
+    let hash = input_hash(input);
+    cache
+        .days
+        .get_mut("aoc01")
+        .unwrap()
+        .get_mut(&hash)
+        .unwrap()
+        .get_mut("part1")
+        .unwrap()
+        .version += 1;
+    assert!(cache.get(puzzle, &hash, Part::One).is_none());
+    let overrides: Overrides = [("start".to_string(), 0.into())].into_iter().collect();
+    let solved = solve(&mut cache, puzzle, input, &overrides, Some(Part::Two), None);
+    assert!(matches!(
+        solved[..],
+        [
+            _,
+            Phase {
+                outcome: Outcome::Solved(_),
+                ..
+            }
+        ]
+    ));
+    Ok(())
+}
+
+#[test]
+fn cache_parts_without_puzzle() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc-cache-unsolved-{}.toml", std::process::id()));
+    let mut cache = Cache::load(&path)?;
+    let puzzle = crate::days::find(12).unwrap();
+    let input = "0:\n###\n#..\n###\n\n3x3: 1\n";
+    solve(&mut cache, puzzle, input, &Overrides::new(), None, None);
+    cache.save()?;
+    let mut cache = Cache::load(&path)?;
+    std::fs::remove_file(&path)?;
+
+    let solved = solve(&mut cache, puzzle, input, &Overrides::new(), None, None);
+    let outcomes: Vec<_> = solved.into_iter().map(|p| (p.part, p.outcome)).collect();
+    assert_eq!(
+        outcomes,
+        [
+            (Some(Part::One), Outcome::Cached("1".to_string())),
+            (Some(Part::Two), Outcome::Solved(Answer::Unsolved)),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_common::{Answer, Part, err};
+
+use crate::{DayName, Result};
+
+const YEAR: u16 = 2025;
+
+/// Sent with every request so the Advent of Code admins know who to contact.
+const USER_AGENT: &str = concat!(
+    "github.com/livexia/advent-of-code-2025 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Authenticated client for adventofcode.com, or any server speaking the same
+/// protocol when `$AOC_URL` is set.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent,
+        }
+    }
+
+    /// Client for `$AOC_URL` (adventofcode.com by default) authenticated with
+    /// the session token from `$AOC_SESSION` or the session config file.
+    pub fn from_env() -> Result<Self> {
+        let base_url =
+            std::env::var("AOC_URL").unwrap_or_else(|_| "https://adventofcode.com".to_string());
+        Ok(Self::new(base_url, session()?))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.base_url)
+    }
+
+    /// Download the personal puzzle input of `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        let response = self
+            .agent
+            .get(&self.url(day, "/input"))
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => {
+                err!("the session token was rejected, log in again and update it")
+            }
+            Err(ureq::Error::Status(404, _)) => {
+                err!("the input of {} is not available yet", DayName(day))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Post `answer` for `part` of `day` and return the response page.
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<String> {
+        let level = part.to_string();
+        let answer = answer.to_string();
+        let response = self
+            .agent
+            .post(&self.url(day, "/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &level), ("answer", &answer)]);
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(400, _)) => {
+                err!("the session token was rejected, log in again and update it")
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+fn session_file() -> Option<PathBuf> {
+    let config = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+    Some(config.join("aoc").join("session"))
+}
+
+fn session() -> Result<String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+    let Some(path) = session_file() else {
+        return err!("no session token, set AOC_SESSION");
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => err!(
+            "no session token, set AOC_SESSION or write it to {}",
+            path.display()
+        ),
+    }
+}
+
+/// One-shot HTTP server on localhost standing in for adventofcode.com. Every
+/// accepted connection is answered with the next of `responses`, the raw
+/// requests are returned by the handle once all responses are sent.
+#[cfg(test)]
+pub fn mock_server(responses: Vec<String>) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = vec![];
+        for response in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(l) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = l.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            requests.push(request);
+
+            let mut stream = reader.into_inner();
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (url, handle)
+}
+
+/// Full HTTP response with `status` (e.g. `200 OK`) and `body`.
+#[cfg(test)]
+pub fn response(status: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+#[test]
+fn input_request() {
+    let (url, server) = mock_server(vec![response("200 OK", "L68\nR48\n")]);
+    let client = Client::new(url, "abc123");
+    assert_eq!(client.input(1).unwrap(), "L68\nR48\n");
+
+    let request = server.join().unwrap().remove(0);
+    assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+    assert!(request.contains("Cookie: session=abc123\r\n"));
+    assert!(request.contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+}
+
+#[test]
+fn input_errors() {
+    let (url, server) = mock_server(vec![
+        response("400 Bad Request", "Puzzle inputs differ by user."),
+        response(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint.",
+        ),
+    ]);
+    let client = Client::new(url, "expired");
+    assert!(client.input(3).unwrap_err().to_string().contains("session"));
+    assert!(
+        client
+            .input(12)
+            .unwrap_err()
+            .to_string()
+            .contains("not available")
+    );
+    server.join().unwrap();
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use aoc_common::params::Overrides;
+use aoc_common::{Answer, Parsed, Part, Puzzle, err};
+
+use crate::phases::{self, Outcome, Phase};
+use crate::{DayName, Result};
+
+fn solve(parsed: &dyn Parsed, part: Part, name: &str) -> Result<Answer> {
+    if name == "main" {
+        parsed.solve(part)
+    } else {
+        parsed
+            .solve_alternate(part, name)
+            .expect("alternate is listed by the parsed input")
+    }
+}
+
+/// The first case of `input` (see [`aoc_common::Solution::cases`]) on which
+/// the alternate `name` disagrees with the main implementation of `part`,
+/// with its index and both answers.
+pub fn first_diverging_case<'a>(
+    puzzle: &Puzzle,
+    input: &'a str,
+    overrides: &Overrides,
+    part: Part,
+    name: &str,
+) -> Result<Option<(usize, &'a str, Answer, Answer)>> {
+    for (i, case) in puzzle.cases(input).into_iter().enumerate() {
+        let parsed = puzzle.parse_with(case, overrides)?;
+        let main = parsed.solve(part)?;
+        let alternate = solve(&*parsed, part, name)?;
+        if main != alternate {
+            return Ok(Some((i + 1, case, main, alternate)));
+        }
+    }
+    Ok(None)
+}
+
+/// Solve every part of `input`, parsed with `overrides`, with every
+/// implementation, the slow ones included, and print the answers and timings side by side. When an
+/// alternate disagrees with the main implementation the first diverging case
+/// is printed. Implementations running longer than `timeout` are given up on.
+/// Returns whether all implementations agree.
+pub fn crosscheck(
+    puzzle: &Puzzle,
+    input: &str,
+    overrides: &Overrides,
+    timeout: Option<Duration>,
+) -> Result<bool> {
+    let phases = phases::solve(puzzle, input, overrides, None, true, timeout);
+    for phase in &phases {
+        phase.parse_error()?;
+    }
+    println!("== {}", DayName(puzzle.day()));
+
+    let mut ok = true;
+    for part in Part::ALL {
+        let part_phases: Vec<&Phase> = phases.iter().filter(|p| p.part == Some(part)).collect();
+        if part_phases.len() == 1 {
+            println!("part {part}: main only");
+            continue;
+        }
+
+        let mut answers = vec![];
+        for phase in part_phases {
+            let name = phase.name;
+            match &phase.outcome {
+                Outcome::Solved(answer) => {
+                    println!(
+                        "part {part} {name:<12} {:>20} {:>12.2?}",
+                        answer.to_string(),
+                        phase.elapsed
+                    );
+                    answers.push((name, answer));
+                }
+                Outcome::TimedOut => {
+                    println!(
+                        "part {part} {name:<12} {:>20} {:>12.2?}",
+                        "timed out", phase.elapsed
+                    );
+                    ok = false;
+                }
+                Outcome::Failed(e) => return err!("part {part} {name}: {e}"),
+                Outcome::Parsed | Outcome::Cached(_) => unreachable!("parts are solved"),
+            }
+        }
+
+        let Some(&("main", main)) = answers.first() else {
+            continue;
+        };
+        for &(name, answer) in &answers[1..] {
+            if answer == main {
+                continue;
+            }
+            ok = false;
+            println!("part {part}: {name} DISAGREES, {answer} instead of {main}");
+            match first_diverging_case(puzzle, input, overrides, part, name)? {
+                Some((i, case, main, alternate)) => {
+                    println!("first diverging case #{i}: {case}");
+                    println!("  main: {main}, {name}: {alternate}");
+                }
+                None => println!("no single case diverges"),
+            }
+        }
+    }
+    Ok(ok)
+}
+
+#[test]
+fn find_diverging_case() -> Result<()> {
+    let puzzle = Puzzle::of::<aoc_common::testing::Sum>();
+    assert!(crosscheck(&puzzle, "1,20,99", &Overrides::new(), None)?);
+    assert!(!crosscheck(
+        &puzzle,
+        "1,20,123,99,456",
+        &Overrides::new(),
+        None
+    )?);
+
+    let diverging = first_diverging_case(
+        &puzzle,
+        "1,20,123,99,456",
+        &Overrides::new(),
+        Part::One,
+        "wrapping",
+    )?;
+    assert_eq!(
+        diverging,
+        Some((3, "123", Answer::from(123u32), Answer::from(23u32)))
+    );
+    Ok(())
+}
+
+#[test]
+fn crosscheck_examples() -> Result<()> {
+    for (day, example) in [
+        (
+            2,
+            "11-22,95-115,998-1012,1188511880-1188511890,222220-222224",
+        ),
+        (
+            3,
+            "987654321111111\n811111111111119\n234234234234278\n818181911112111",
+        ),
+    ] {
+        assert!(crosscheck(
+            crate::days::find(day).unwrap(),
+            example,
+            &Overrides::new(),
+            None
+        )?);
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use aoc_common::Puzzle;
+
+/// Every day that has a solution crate in the workspace, in order.
+pub static DAYS: &[Puzzle] = &[
+    Puzzle::of::<aoc01::Day01>(),
+    Puzzle::of::<aoc02::Day02>(),
+    Puzzle::of::<aoc03::Day03>(),
+    Puzzle::of::<aoc04::Day04>(),
+    Puzzle::of::<aoc05::Day05>(),
+    Puzzle::of::<aoc06::Day06>(),
+    Puzzle::of::<aoc07::Day07>(),
+    Puzzle::of::<aoc08::Day08>(),
+    Puzzle::of::<aoc09::Day09>(),
+    Puzzle::of::<aoc10::Day10>(),
+    Puzzle::of::<aoc11::Day11>(),
+    Puzzle::of::<aoc12::Day12>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    DAYS.iter().find(|p| p.day() == day)
+}
+
+/// Check the fixtures of every day, see [`aoc_common::fixtures`].
+#[test]
+fn fixtures() -> aoc_common::Result<()> {
+    for puzzle in DAYS {
+        aoc_common::fixtures::check(puzzle)?;
+    }
+    Ok(())
+}
+
+/// Differential testing of every day with a generator, see
+/// [`aoc_common::fuzz`].
+#[test]
+fn generated_inputs() -> aoc_common::Result<()> {
+    for puzzle in DAYS {
+        if let Some(found) = aoc_common::fuzz::fuzz(puzzle, 2025, 50)? {
+            panic!("aoc{:02} disagrees: {found:?}", puzzle.day());
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Extract the examples of a saved puzzle page into fixtures.
+//!
+//! The page is split into its `<article>` elements, one per part. The example
+//! of a part is the first `<pre><code>` block of its article without any
+//! emphasis (emphasized blocks are walkthroughs, not inputs), part 2 reuses
+//! the example of part 1 when it has none. The expected answer of a part is the
+//! last emphasized `<code><em>` in its article. The heuristic fits most days,
+//! the written fixtures are plain files and can be corrected by hand.
+
+use aoc_common::Part;
+use aoc_common::fixtures::Fixture;
+
+/// Contents of every `<open>...</close>` element in `html`, in order.
+fn elements<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)?;
+        let after = &rest[start + open.len()..];
+        // skip the attributes of the opening tag
+        let after = if open.ends_with('>') {
+            after
+        } else {
+            &after[after.find('>')? + 1..]
+        };
+        let end = after.find(close)?;
+        rest = &after[end + close.len()..];
+        Some(&after[..end])
+    })
+}
+
+/// Text of an html fragment: tags removed and entities decoded.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn answer(article: &str) -> Option<String> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| {
+        let start = article.rfind(open)?;
+        Some((start, elements(&article[start..], open, close).next()?))
+    })
+    .max_by_key(|&(start, _)| start)
+    .map(|(_, answer)| text(answer))
+}
+
+/// The examples of `page` with the answers the text gives for them, named
+/// `example-1`, `example-2`, ...
+pub fn extract(page: &str) -> Vec<Fixture> {
+    let mut fixtures: Vec<Fixture> = vec![];
+    for (part, article) in Part::ALL
+        .into_iter()
+        .zip(elements(page, "<article", "</article>"))
+    {
+        let input = elements(article, "<pre><code>", "</code></pre>")
+            .find(|block| !block.contains("<em>"))
+            .map(text);
+        let index = match input {
+            Some(input) if fixtures.last().is_none_or(|f| f.input != input) => {
+                fixtures.push(Fixture {
+                    name: format!("example-{}", fixtures.len() + 1),
+                    input,
+                    part1: None,
+                    part2: None,
+                    params: Default::default(),
+                    differs: None,
+                });
+                fixtures.len() - 1
+            }
+            _ if fixtures.is_empty() => continue,
+            _ => fixtures.len() - 1,
+        };
+        let answer = answer(article);
+        match part {
+            Part::One => fixtures[index].part1 = answer,
+            Part::Two => fixtures[index].part2 = answer,
+        }
+    }
+    fixtures
+}
+
+#[test]
+fn extract_examples() {
+    let page = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2>
+<p>For example, suppose the attached document contained the following rotations:</p>
+<pre><code>L68
+L30
+R48
+</code></pre>
+<p>Because the dial points at <code>0</code> a total of three times during this process, the password in this example is <code><em>3</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1100</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Following the same rotations as in the above example, the dial points at zero a few extra times:</p>
+<pre><code>- The dial starts by pointing at <em>50</em>.
+</code></pre>
+<p>In this example, the new password would be <code><em>6</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>6358</code>.</p>
+</main>"#;
+    let fixtures = extract(page);
+    assert_eq!(fixtures.len(), 1);
+    assert_eq!(fixtures[0].name, "example-1");
+    assert_eq!(fixtures[0].input, "L68\nL30\nR48\n");
+    assert_eq!(fixtures[0].part1.as_deref(), Some("3"));
+    assert_eq!(fixtures[0].part2.as_deref(), Some("6"));
+
+    let page = r#"<article><pre><code>a -&gt; b
+</code></pre><p><em><code>1</code></em> and <code><em>2</em></code></p></article>
+<article><pre><code>c -&gt; d
+</code></pre><p>Now it is <code><em>5</em></code>.</p></article>"#;
+    let fixtures = extract(page);
+    assert_eq!(fixtures.len(), 2);
+    assert_eq!(fixtures[0].input, "a -> b\n");
+    assert_eq!(fixtures[0].part1.as_deref(), Some("2"));
+    assert_eq!(fixtures[0].part2, None);
+    assert_eq!(fixtures[1].name, "example-2");
+    assert_eq!(fixtures[1].input, "c -> d\n");
+    assert_eq!(fixtures[1].part2.as_deref(), Some("5"));
+}
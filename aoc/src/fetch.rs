@@ -0,0 +1,52 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::Result;
+use crate::client::Client;
+
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+impl fmt::Display for Fetched {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cached => write!(f, "cached"),
+            Self::Downloaded => write!(f, "downloaded"),
+        }
+    }
+}
+
+/// Download the input of `day` to `path`, creating its directory. An input
+/// that is already there is never fetched again, a missing or blank one is
+/// downloaded. The client is only built when a download is needed.
+pub fn fetch(day: u8, path: &Path, client: impl FnOnce() -> Result<Client>) -> Result<Fetched> {
+    if std::fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty()) {
+        return Ok(Fetched::Cached);
+    }
+    let input = client()?.input(day)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, input)?;
+    Ok(Fetched::Downloaded)
+}
+
+#[test]
+fn fetch_once() {
+    use crate::client::{mock_server, response};
+
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let path = dir.join("aoc05").join("input").join("input.txt");
+    let (url, server) = mock_server(vec![response("200 OK", "3-5\n\n1\n")]);
+
+    let fetched = fetch(5, &path, || Ok(Client::new(url, "abc123"))).unwrap();
+    assert!(matches!(fetched, Fetched::Downloaded));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "3-5\n\n1\n");
+    assert_eq!(server.join().unwrap().len(), 1);
+
+    let fetched = fetch(5, &path, || panic!("cached input fetched again")).unwrap();
+    assert!(matches!(fetched, Fetched::Cached));
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,849 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc_common::fixtures;
+use aoc_common::fuzz::Rng;
+use aoc_common::params::{self, Overrides};
+use aoc_common::registry::Registry;
+use aoc_common::{Answer, Part, Puzzle, err, input_path, read_input, read_path, workspace_root};
+use clap::{Args, Parser, Subcommand};
+
+mod alloc;
+mod batch;
+mod bench;
+mod cache;
+mod client;
+mod crosscheck;
+mod days;
+mod examples;
+mod fetch;
+mod new;
+mod phases;
+mod repl;
+mod report;
+mod submit;
+mod verify;
+
+type Result<T> = ::std::result::Result<T, Box<dyn Error>>;
+
+/// Runner for every Advent of Code 2025 day in the workspace.
+#[derive(Parser)]
+#[command(name = "aoc", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day against its `aocNN/input/input.txt`
+    Run {
+        /// Day number, or `all` to run every day in order
+        day: Selection,
+        /// Only run the given part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+        /// Solve every part even when its answer is cached
+        #[arg(long)]
+        no_cache: bool,
+        /// Solve this input instead of the personal one, `-` for stdin, may be
+        /// repeated and gzip-compressed
+        #[arg(long = "input", value_name = "PATH")]
+        inputs: Vec<PathBuf>,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Run days and write a JSON or CSV report with the timing of every phase
+    Report {
+        /// Day number, or `all` to report every day
+        day: Selection,
+        #[arg(long, value_enum, default_value_t = report::Format::Json)]
+        format: report::Format,
+        /// Write the report to a file instead of stdout
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+        /// Solve every part even when its answer is cached
+        #[arg(long)]
+        no_cache: bool,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Time each phase of days repeatedly and compare with the previous run
+    ///
+    /// Runs are recorded in `bench.toml` (or `$AOC_BENCH`), a phase whose
+    /// median is slower than the previous run on the same input by more than
+    /// the threshold is flagged.
+    Bench {
+        /// Day number, or `all` to benchmark every day
+        day: Selection,
+        /// Timed runs per phase
+        #[arg(long, default_value_t = 20)]
+        runs: usize,
+        /// Untimed runs per phase before the timed ones
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Allowed slowdown of the median, in percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Do not record this run in the history
+        #[arg(long)]
+        no_record: bool,
+    },
+    /// Compare the answers of a day with the answer registry
+    Verify {
+        /// Day number, or `all` to verify every day
+        day: Selection,
+        /// Registry file, defaults to `$AOC_ANSWERS` or `answers.toml`
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Add the answers of inputs that are not in the registry yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Run a day on every input of a directory and check the answers
+    ///
+    /// Inputs are solved in parallel and compared with their sidecar answers,
+    /// `alice.answers.toml` for `alice.txt`, if there is one. Prints a table
+    /// of the answers, timings and results of every input.
+    Batch {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// Directory of inputs, possibly gzip-compressed
+        dir: PathBuf,
+        /// Inputs solved at the same time, defaults to the number of cores
+        #[arg(long, short)]
+        jobs: Option<usize>,
+        /// Give up on a part after this many seconds
+        #[arg(long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Parse an input once and ask questions about it interactively
+    ///
+    /// Each day has its own queries, e.g. `paths you out` for day 11, `help`
+    /// lists them. Command names complete with Tab.
+    Repl {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// Explore this input instead of the personal one, `-` for stdin
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Check the assumptions the solution of a day makes about its input
+    Validate {
+        /// Day number, or `all` to validate every day
+        day: Selection,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Run every implementation of a day side by side and compare them
+    ///
+    /// Slow exploratory implementations are included. When they disagree the
+    /// first diverging case of the input is reported.
+    Crosscheck {
+        /// Day number, or `all` to check every day
+        day: Selection,
+        /// Give up on an implementation after this many seconds
+        #[arg(long, value_parser = parse_timeout)]
+        timeout: Option<Duration>,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Print the parameters of days with their defaults
+    ///
+    /// The output is a config file for `--params` with a table per day, days
+    /// without parameters are left out.
+    Params {
+        /// Day number, or `all` for every day
+        day: Selection,
+    },
+    /// Compare the implementations of each day on random inputs
+    ///
+    /// A disagreement is shrunk to a small input and written to the fixtures
+    /// of the day, with the answer of the main implementation as expected
+    /// answer.
+    Fuzz {
+        /// Day number, or `all` to fuzz every day with a generator
+        day: Selection,
+        /// Number of generated inputs per day
+        #[arg(long, default_value_t = 100)]
+        runs: usize,
+        /// Seed of the generator, random by default
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Download the puzzle input of a day into `aocNN/input/input.txt`
+    ///
+    /// The session token is read from `$AOC_SESSION` or
+    /// `~/.config/aoc/session`. Inputs that are already downloaded are kept.
+    Fetch {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+    },
+    /// Write the examples of a saved puzzle page to `aocNN/fixtures`
+    ///
+    /// Fixtures with known answers are checked by the `fixtures` test of the
+    /// runner for every day.
+    Examples {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// Puzzle description saved from the browser
+        page: PathBuf,
+    },
+    /// Create the crate of a new day and register it in the workspace
+    New {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+    },
+    /// Solve a part and submit its answer
+    ///
+    /// Submissions are kept in `submissions.toml` (or `$AOC_SUBMISSIONS`), a
+    /// known-wrong answer is never sent again and nothing is sent while the
+    /// server asks to wait. Correct answers are added to the answer registry.
+    Submit {
+        /// Day number
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+        day: u8,
+        /// Part to submit
+        #[arg(value_parser = parse_part)]
+        part: Part,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum Selection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        match s.parse::<u8>() {
+            Ok(day) if days::find(day).is_some() => Ok(Self::Day(day)),
+            _ => Err(format!("expected `all` or a solved day, got {s:?}")),
+        }
+    }
+}
+
+impl Selection {
+    fn puzzles(self) -> Vec<&'static Puzzle> {
+        match self {
+            Self::All => days::DAYS.iter().collect(),
+            Self::Day(day) => days::find(day).into_iter().collect(),
+        }
+    }
+}
+
+/// Puzzle constants to replace, see [`aoc_common::params`].
+#[derive(Args)]
+struct ParamArgs {
+    /// Replace a puzzle constant of the day
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, toml::Value)>,
+    /// Config file with the parameters of each day in an `[aocNN]` table,
+    /// `--param` takes precedence
+    #[arg(long = "params", value_name = "FILE")]
+    file: Option<PathBuf>,
+}
+
+impl ParamArgs {
+    /// The parameters of `day` in the config file and on the command line.
+    fn overrides(&self, day: u8) -> Result<Overrides> {
+        let mut overrides = Overrides::new();
+        if let Some(path) = &self.file {
+            let config = std::fs::read_to_string(path)
+                .map_err(|e| format!("unable to read {}: {e}", path.display()))?;
+            let mut config: toml::Table =
+                toml::from_str(&config).map_err(|e| format!("{}: {e}", path.display()))?;
+            match config.remove(&DayName(day).to_string()) {
+                Some(toml::Value::Table(table)) => overrides = table,
+                Some(_) => {
+                    return Err(
+                        format!("{}: {} is not a table", path.display(), DayName(day)).into(),
+                    );
+                }
+                None => {}
+            }
+        }
+        overrides.extend(self.params.iter().cloned());
+        Ok(overrides)
+    }
+}
+
+struct DayName(u8);
+
+impl fmt::Display for DayName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "aoc{:02}", self.0)
+    }
+}
+
+fn parse_part(s: &str) -> std::result::Result<Part, String> {
+    s.parse::<u8>().map_err(|e| e.to_string())?.try_into()
+}
+
+fn parse_param(s: &str) -> std::result::Result<(String, toml::Value), String> {
+    params::assignment(s).map_err(|e| e.to_string())
+}
+
+fn parse_timeout(s: &str) -> std::result::Result<Duration, String> {
+    let seconds: f64 = s.parse().map_err(|e| format!("{e}"))?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Solve `part`, or both parts, and print the answers with the time each
+/// part took. Returns whether every part got an answer in time.
+fn run_day(
+    puzzle: &Puzzle,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    input: &str,
+    params: &ParamArgs,
+    cache: Option<&mut cache::Cache>,
+) -> Result<bool> {
+    let overrides = params.overrides(puzzle.day())?;
+    let phases = match cache {
+        Some(cache) => cache::solve(cache, puzzle, input, &overrides, part, timeout),
+        None => phases::solve(puzzle, input, &overrides, part, false, timeout),
+    };
+    let mut ok = true;
+    for phase in phases {
+        phase.parse_error()?;
+        let Some(p) = phase.part else {
+            continue;
+        };
+        match phase.outcome {
+            phases::Outcome::Solved(Answer::Unsolved) | phases::Outcome::Parsed => {}
+            phases::Outcome::Solved(answer) => {
+                println!("part {p}: {answer}");
+                println!("> Time elapsed is: {:?}", phase.elapsed);
+            }
+            phases::Outcome::Cached(answer) => {
+                println!("part {p}: {answer}");
+                println!("> Cached, took {:?}", phase.elapsed);
+            }
+            phases::Outcome::Failed(e) => return Err(e.into()),
+            phases::Outcome::TimedOut => {
+                println!("part {p}: timed out after {:.2?}", phase.elapsed);
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+/// Run the selected days on their personal inputs, or on `inputs` when
+/// there are any, which takes a single day.
+fn run_days(
+    day: Selection,
+    inputs: &[PathBuf],
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    params: &ParamArgs,
+    no_cache: bool,
+) -> Result<bool> {
+    if !inputs.is_empty() && matches!(day, Selection::All) {
+        return err!("`--input` takes a single day, not `all`");
+    }
+    let mut cache = (!no_cache)
+        .then(|| cache::Cache::load(cache::Cache::default_path()))
+        .transpose()?;
+    let mut ok = true;
+    for puzzle in day.puzzles() {
+        let runs: Vec<(String, Result<String>)> = if inputs.is_empty() {
+            vec![(DayName(puzzle.day()).to_string(), read_input(puzzle.day()))]
+        } else {
+            inputs
+                .iter()
+                .map(|path| {
+                    let name = format!("{} {}", DayName(puzzle.day()), path.display());
+                    (name, read_path(path))
+                })
+                .collect()
+        };
+        for (name, input) in runs {
+            println!("== {name}");
+            match input
+                .and_then(|input| run_day(puzzle, part, timeout, &input, params, cache.as_mut()))
+            {
+                Ok(solved) => ok &= solved,
+                Err(e) => {
+                    eprintln!("{name}: {e}");
+                    ok = false;
+                }
+            }
+        }
+    }
+    if let Some(cache) = cache {
+        cache.save()?;
+    }
+    Ok(ok)
+}
+
+/// Run `day` on the inputs of `dir` and print the table, returns whether no
+/// input failed.
+fn batch_day(
+    day: u8,
+    dir: &Path,
+    jobs: Option<usize>,
+    timeout: Option<Duration>,
+    params: &ParamArgs,
+) -> Result<bool> {
+    let Some(puzzle) = days::find(day) else {
+        return err!("no solution yet");
+    };
+    let overrides = params.overrides(day)?;
+    let jobs = jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+    let results = batch::batch(puzzle, dir, &overrides, timeout, jobs)?;
+    if results.is_empty() {
+        return err!("no inputs in {}", dir.display());
+    }
+    batch::print(&results);
+    Ok(results
+        .iter()
+        .all(|r| matches!(r.status, batch::Status::Pass | batch::Status::Unchecked)))
+}
+
+fn repl_day(day: u8, input: Option<PathBuf>, params: &ParamArgs) -> Result<()> {
+    let Some(puzzle) = days::find(day) else {
+        return err!("no solution yet");
+    };
+    let input = match input {
+        Some(path) => read_path(path)?,
+        None => read_input(day)?,
+    };
+    repl::repl(puzzle, &input, &params.overrides(day)?)
+}
+
+fn verify_days(day: Selection, answers: Option<PathBuf>, record: bool) -> Result<bool> {
+    let mut registry = Registry::load(answers.unwrap_or_else(Registry::default_path))?;
+    let mut ok = true;
+    for puzzle in day.puzzles() {
+        let verified = read_input(puzzle.day())
+            .and_then(|input| verify::verify(puzzle, &input, &mut registry, record));
+        match verified {
+            Ok(verified) => ok &= verified,
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(puzzle.day()));
+                ok = false;
+            }
+        }
+    }
+    if record {
+        registry.save()?;
+    }
+    Ok(ok)
+}
+
+fn fetch_day(day: u8) -> Result<()> {
+    let path = input_path(day);
+    let fetched = fetch::fetch(day, &path, client::Client::from_env)?;
+    println!("{}: {fetched} {}", DayName(day), path.display());
+    Ok(())
+}
+
+fn submit_part(day: u8, part: Part) -> Result<()> {
+    let Some(puzzle) = days::find(day) else {
+        return err!("no solution yet");
+    };
+    let input = read_input(day)?;
+    let answer = puzzle.parse(&input)?.solve(part)?;
+    println!("{} part {part}: submitting {answer}", DayName(day));
+
+    let mut history = submit::History::load(submit::History::default_path())?;
+    let outcome = submit::submit(
+        &mut history,
+        client::Client::from_env,
+        day,
+        part,
+        &answer,
+        submit::now(),
+    );
+    history.save()?;
+    let outcome = outcome?;
+    println!("{} part {part}: {outcome}", DayName(day));
+
+    if matches!(outcome, submit::Outcome::Correct) {
+        let mut registry = Registry::load(Registry::default_path())?;
+        let hash = aoc_common::registry::input_hash(&input);
+        registry.set(day, &hash, part, &answer);
+        registry.save()?;
+    }
+    Ok(())
+}
+
+fn extract_examples(day: u8, page: &Path) -> Result<()> {
+    let page = std::fs::read_to_string(page)
+        .map_err(|e| format!("unable to read {}: {e}", page.display()))?;
+    let fixtures = examples::extract(&page);
+    if fixtures.is_empty() {
+        return Err("no example found in the page".into());
+    }
+    for fixture in fixtures {
+        let path = fixtures::save(day, &fixture)?;
+        let answers = Part::ALL.map(|p| fixture.expected(p).unwrap_or("-"));
+        println!(
+            "{}: part 1 {}, part 2 {}",
+            path.display(),
+            answers[0],
+            answers[1]
+        );
+    }
+    Ok(())
+}
+
+/// Print every assumption of the day and whether `input` holds to it.
+/// Returns whether it holds to all of them.
+fn validate_day(puzzle: &Puzzle, input: &str, overrides: &Overrides) -> Result<bool> {
+    let parsed = puzzle.parse_with(input, overrides)?;
+    let name = DayName(puzzle.day());
+    let checks = parsed.validate();
+    if checks.is_empty() {
+        println!("{name}: no assumptions");
+        return Ok(true);
+    }
+    let mut ok = true;
+    for (assumption, check) in checks {
+        match check {
+            Ok(()) => println!("{name}: {assumption}: ok"),
+            Err(e) => {
+                println!("{name}: {assumption}: VIOLATED, {e}");
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+fn fuzz_days(day: Selection, runs: usize, seed: Option<u64>) -> Result<bool> {
+    let seed = seed.unwrap_or_else(|| {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH);
+        now.map_or(0, |d| d.as_nanos() as u64)
+    });
+    println!("seed {seed}");
+    let mut ok = true;
+    for puzzle in day.puzzles() {
+        let name = DayName(puzzle.day());
+        if puzzle.generate(&mut Rng::new(seed)).is_none() {
+            println!("{name}: no generator");
+            continue;
+        }
+        let Some(found) = aoc_common::fuzz::fuzz(puzzle, seed, runs)? else {
+            println!("{name}: {runs} inputs agree");
+            continue;
+        };
+        ok = false;
+        println!(
+            "{name} part {}: {} gives {} instead of {} on",
+            found.part, found.name, found.alternate, found.main
+        );
+        println!("{}", found.input);
+
+        let hash = aoc_common::registry::input_hash(&found.input);
+        let main = Some(found.main.to_string());
+        let fixture = fixtures::Fixture {
+            name: format!("regression-{}", &hash[..8]),
+            input: format!("{}\n", found.input.trim_end()),
+            part1: main.clone().filter(|_| found.part == Part::One),
+            part2: main.filter(|_| found.part == Part::Two),
+            params: Default::default(),
+            differs: None,
+        };
+        println!(
+            "written to {}",
+            fixtures::save(puzzle.day(), &fixture)?.display()
+        );
+    }
+    Ok(ok)
+}
+
+fn bench_days(
+    day: Selection,
+    runs: usize,
+    warmup: usize,
+    threshold: f64,
+    record: bool,
+) -> Result<bool> {
+    let mut history = bench::History::load(bench::History::default_path())?;
+    let mut ok = true;
+    for puzzle in day.puzzles() {
+        let name = DayName(puzzle.day());
+        let run = match read_input(puzzle.day())
+            .and_then(|input| bench::bench(puzzle, &input, runs, warmup))
+        {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("{name}: {e}");
+                ok = false;
+                continue;
+            }
+        };
+        println!("== {name} ({runs} runs)");
+        let previous = history.previous(puzzle.day(), &run.input_hash);
+        let regressed = previous.map_or(vec![], |previous| {
+            bench::regressions(previous, &run, threshold)
+        });
+        for (phase, stats) in &run.phases {
+            let ns = std::time::Duration::from_nanos;
+            print!(
+                "{phase:<6} min {:>10.2?} median {:>10.2?} p95 {:>10.2?}",
+                ns(stats.min_ns),
+                ns(stats.median_ns),
+                ns(stats.p95_ns)
+            );
+            let before = previous.and_then(|previous| previous.phases.get(phase));
+            match before.and_then(|before| bench::change(before.median_ns, stats.median_ns)) {
+                Some(change) if regressed.iter().any(|(p, _)| p == phase) => {
+                    println!("  {change:+.1}% REGRESSED")
+                }
+                Some(change) => println!("  {change:+.1}%"),
+                None => println!(),
+            }
+        }
+        ok &= regressed.is_empty();
+        if record {
+            history.record(puzzle.day(), run);
+        }
+    }
+    if record {
+        history.save()?;
+    }
+    Ok(ok)
+}
+
+fn write_report(
+    day: Selection,
+    format: report::Format,
+    output: Option<PathBuf>,
+    timeout: Option<Duration>,
+    params: &ParamArgs,
+    no_cache: bool,
+) -> Result<()> {
+    let mut cache = (!no_cache)
+        .then(|| cache::Cache::load(cache::Cache::default_path()))
+        .transpose()?;
+    let mut report = report::Report::new();
+    for puzzle in day.puzzles() {
+        let overrides = params.overrides(puzzle.day())?;
+        let input = read_input(puzzle.day());
+        report.add(puzzle, input, &overrides, timeout, cache.as_mut());
+    }
+    if let Some(cache) = cache {
+        cache.save()?;
+    }
+    let report = report.render(format)?;
+    match output {
+        Some(path) => std::fs::write(path, report)?,
+        None => print!("{report}"),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            timeout,
+            no_cache,
+            inputs,
+            params,
+        } => match run_days(day, &inputs, part, timeout, &params, no_cache) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Report {
+            day,
+            format,
+            output,
+            timeout,
+            no_cache,
+            params,
+        } => match write_report(day, format, output, timeout, &params, no_cache) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Bench {
+            day,
+            runs,
+            warmup,
+            threshold,
+            no_record,
+        } => match bench_days(day, runs, warmup, threshold, !no_record) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Verify {
+            day,
+            answers,
+            record,
+        } => match verify_days(day, answers, record) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Submit { day, part } => match submit_part(day, part) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(day));
+                ExitCode::FAILURE
+            }
+        },
+        Command::Examples { day, page } => match extract_examples(day, &page) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(day));
+                ExitCode::FAILURE
+            }
+        },
+        Command::New { day } => match new::scaffold(&workspace_root(), day) {
+            Ok(dir) => {
+                println!("created {}", dir.display());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(day));
+                ExitCode::FAILURE
+            }
+        },
+        Command::Batch {
+            day,
+            dir,
+            jobs,
+            timeout,
+            params,
+        } => match batch_day(day, &dir, jobs, timeout, &params) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(day));
+                ExitCode::FAILURE
+            }
+        },
+        Command::Repl { day, input, params } => match repl_day(day, input, &params) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(day));
+                ExitCode::FAILURE
+            }
+        },
+        Command::Validate { day, params } => {
+            let mut ok = true;
+            for puzzle in day.puzzles() {
+                let validated = params.overrides(puzzle.day()).and_then(|overrides| {
+                    validate_day(puzzle, &read_input(puzzle.day())?, &overrides)
+                });
+                match validated {
+                    Ok(valid) => ok &= valid,
+                    Err(e) => {
+                        eprintln!("{}: {e}", DayName(puzzle.day()));
+                        ok = false;
+                    }
+                }
+            }
+            if ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Crosscheck {
+            day,
+            timeout,
+            params,
+        } => {
+            let mut ok = true;
+            for puzzle in day.puzzles() {
+                let checked = params.overrides(puzzle.day()).and_then(|overrides| {
+                    let input = read_input(puzzle.day())?;
+                    crosscheck::crosscheck(puzzle, &input, &overrides, timeout)
+                });
+                match checked {
+                    Ok(agree) => ok &= agree,
+                    Err(e) => {
+                        eprintln!("{}: {e}", DayName(puzzle.day()));
+                        ok = false;
+                    }
+                }
+            }
+            if ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Params { day } => {
+            let config: toml::Table = day
+                .puzzles()
+                .into_iter()
+                .map(|puzzle| (DayName(puzzle.day()).to_string(), puzzle.params()))
+                .filter(|(_, params)| !params.is_empty())
+                .map(|(name, params)| (name, toml::Value::Table(params)))
+                .collect();
+            match toml::to_string(&config) {
+                Ok(config) => {
+                    print!("{config}");
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Fuzz { day, runs, seed } => match fuzz_days(day, runs, seed) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Fetch { day } => match fetch_day(day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}: {e}", DayName(day));
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
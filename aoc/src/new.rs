@@ -0,0 +1,150 @@
+//! Scaffolding of a new day from the templates in `aoc/templates/day`.
+//!
+//! Bump [`TEMPLATE_VERSION`] whenever a template changes, the version a day
+//! was generated from is kept in its `[package.metadata.aoc]`.
+
+use std::path::{Path, PathBuf};
+
+use aoc_common::err;
+
+use crate::{DayName, Result};
+
+pub const TEMPLATE_VERSION: u32 = 4;
+
+/// Template files and where they go in the new crate.
+const TEMPLATES: [(&str, &str); 5] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/day/Cargo.toml.tpl"),
+    ),
+    ("build.rs", include_str!("../templates/day/build.rs.tpl")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.tpl")),
+    ("src/main.rs", include_str!("../templates/day/main.rs.tpl")),
+    (
+        "fixtures/answers.toml",
+        include_str!("../templates/day/answers.toml.tpl"),
+    ),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{name}}", &DayName(day).to_string())
+        .replace("{{Day}}", &format!("Day{day:02}"))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{version}}", &TEMPLATE_VERSION.to_string())
+}
+
+/// Insert `line` into the sorted run of lines starting with `prefix`.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    if lines.contains(&line) {
+        return err!("{} is already registered", line.trim());
+    }
+    let Some(first) = lines.iter().position(|l| l.starts_with(prefix)) else {
+        return err!(
+            "no line starting with {prefix:?} to register {}",
+            line.trim()
+        );
+    };
+    let at = lines[first..]
+        .iter()
+        .take_while(|l| l.starts_with(prefix))
+        .take_while(|&&l| l < line)
+        .count();
+    lines.insert(first + at, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| format!("unable to read {}: {e}", path.display()).into())
+}
+
+/// Create the crate of `day` in the workspace at `root` and register it in the
+/// workspace members, the dependencies of the runner and its table of days.
+/// An existing day is never touched.
+pub fn scaffold(root: &Path, day: u8) -> Result<PathBuf> {
+    let name = DayName(day).to_string();
+    let dir = root.join(&name);
+    if dir.exists() {
+        return err!("{} already exists", dir.display());
+    }
+
+    // edit everything before writing anything, so a workspace that cannot
+    // take the day is left alone
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let days = root.join("aoc").join("src").join("days.rs");
+    let edits = [
+        (&workspace, "    \"aoc", format!("    \"{name}\",")),
+        (
+            &runner,
+            "aoc",
+            format!("{name} = {{ path = \"../{name}\" }}"),
+        ),
+        (
+            &days,
+            "    Puzzle::of::<aoc",
+            format!("    Puzzle::of::<{name}::Day{day:02}>(),"),
+        ),
+    ];
+    let mut edited = vec![];
+    for (path, prefix, line) in edits {
+        let text = insert_sorted(&read(path)?, prefix, &line)
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        edited.push((path, text));
+    }
+    for (path, text) in edited {
+        std::fs::write(path, text)?;
+    }
+
+    for (path, template) in TEMPLATES {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().expect("template paths have a parent"))?;
+        std::fs::write(path, render(template, day))?;
+    }
+    std::fs::create_dir_all(dir.join("input"))?;
+    Ok(dir)
+}
+
+#[test]
+fn scaffold_day() -> Result<()> {
+    let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+    std::fs::create_dir_all(root.join("aoc").join("src"))?;
+    std::fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc-common\",\n    \"aoc01\",\n    \"aoc03\",\n]\n",
+    )?;
+    std::fs::write(
+        root.join("aoc").join("Cargo.toml"),
+        "[dependencies]\nclap = \"4.5\"\naoc-common = { path = \"../aoc-common\" }\naoc01 = { path = \"../aoc01\" }\naoc03 = { path = \"../aoc03\" }\n",
+    )?;
+    std::fs::write(
+        root.join("aoc").join("src").join("days.rs"),
+        "pub static DAYS: &[Puzzle] = &[\n    Puzzle::of::<aoc01::Day01>(),\n    Puzzle::of::<aoc03::Day03>(),\n];\n",
+    )?;
+    std::fs::create_dir_all(root.join("aoc03"))?;
+
+    let dir = scaffold(&root, 2)?;
+    let read = |path: PathBuf| std::fs::read_to_string(path).unwrap();
+    assert!(
+        read(root.join("Cargo.toml")).contains("    \"aoc01\",\n    \"aoc02\",\n    \"aoc03\",")
+    );
+    assert!(read(root.join("aoc").join("Cargo.toml")).contains(
+        "aoc01 = { path = \"../aoc01\" }\naoc02 = { path = \"../aoc02\" }\naoc03 = { path = \"../aoc03\" }"
+    ));
+    assert!(read(root.join("aoc").join("src").join("days.rs")).contains(
+        "    Puzzle::of::<aoc01::Day01>(),\n    Puzzle::of::<aoc02::Day02>(),\n    Puzzle::of::<aoc03::Day03>(),"
+    ));
+    let lib = read(dir.join("src").join("lib.rs"));
+    assert!(lib.contains("impl Solution for Day02 {\n    const DAY: u8 = 2;"));
+    assert!(!lib.contains("{{"));
+    assert!(read(dir.join("Cargo.toml")).contains("name = \"aoc02\""));
+    assert!(dir.join("fixtures").join("answers.toml").is_file());
+    assert!(dir.join("input").is_dir());
+
+    assert!(scaffold(&root, 2).is_err());
+    assert!(scaffold(&root, 3).is_err());
+    std::fs::remove_dir_all(root)?;
+    Ok(())
+}
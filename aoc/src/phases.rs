@@ -0,0 +1,273 @@
+//! Parsing and solving a day on a worker thread, with a time budget for every
+//! phase.
+//!
+//! A phase that runs out of time has its [`Token`] cancelled and is reported
+//! as timed out. Solvers that check their token stop soon after, and the
+//! worker goes on with the next phase. A phase that does not stop within
+//! another budget is left behind, together with the phases after it.
+
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use aoc_common::cancel::{self, Token};
+use aoc_common::params::Overrides;
+use aoc_common::{Answer, Parsed, Part, Puzzle, Result, timed};
+
+use crate::DayName;
+use crate::alloc::{self, AllocStats};
+
+/// Solvers recurse deeply, give workers as much stack as the main thread.
+const STACK_SIZE: usize = 8 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Parsed,
+    Solved(Answer),
+    /// Answer of an earlier run, see [`crate::cache`].
+    Cached(String),
+    Failed(String),
+    TimedOut,
+}
+
+#[derive(Debug, Clone)]
+pub struct Phase {
+    /// `None` for parsing.
+    pub part: Option<Part>,
+    /// `main`, or the name of an alternate.
+    pub name: &'static str,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Only with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+impl Phase {
+    /// Parsing that failed or timed out, as an error.
+    pub fn parse_error(&self) -> Result<()> {
+        match (&self.part, &self.outcome) {
+            (None, Outcome::Failed(e)) => Err(e.as_str().into()),
+            (None, Outcome::TimedOut) => {
+                Err(format!("parsing timed out after {:.2?}", self.elapsed).into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+enum Message {
+    Started(Option<Part>, &'static str, Token),
+    Finished(Phase),
+}
+
+/// Run one phase with a fresh token, false once nobody waits for the phases.
+fn phase(
+    tx: &Sender<Message>,
+    part: Option<Part>,
+    name: &'static str,
+    solve: impl FnOnce() -> Result<Outcome>,
+) -> bool {
+    let token = Token::new();
+    if tx
+        .send(Message::Started(part, name, token.clone()))
+        .is_err()
+    {
+        return false;
+    }
+    let ((outcome, elapsed), alloc) =
+        alloc::measure(|| timed(|| cancel::with_token(&token, solve)));
+    let outcome = outcome.unwrap_or_else(|e| Outcome::Failed(e.to_string()));
+    let phase = Phase {
+        part,
+        name,
+        outcome,
+        elapsed,
+        alloc,
+    };
+    tx.send(Message::Finished(phase)).is_ok()
+}
+
+struct Job {
+    puzzle: Puzzle,
+    input: String,
+    overrides: Overrides,
+    part: Option<Part>,
+    alternates: bool,
+}
+
+fn work(job: Job, tx: Sender<Message>) {
+    let Job {
+        puzzle,
+        input,
+        overrides,
+        part,
+        alternates,
+    } = job;
+    let mut parsed: Option<Box<dyn Parsed>> = None;
+    phase(&tx, None, "parse", || {
+        parsed = Some(puzzle.parse_with(&input, &overrides)?);
+        Ok(Outcome::Parsed)
+    });
+    let Some(parsed) = parsed else {
+        return;
+    };
+
+    for p in Part::ALL {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+        let mut names = vec!["main"];
+        if alternates {
+            let listed = parsed.alternates(true).into_iter();
+            names.extend(listed.filter(|(ap, _)| *ap == p).map(|(_, name)| name));
+        }
+        for name in names {
+            let solved = phase(&tx, Some(p), name, || {
+                let answer = match name {
+                    "main" => parsed.solve(p)?,
+                    _ => parsed
+                        .solve_alternate(p, name)
+                        .expect("alternate is listed by the parsed input")?,
+                };
+                Ok(Outcome::Solved(answer))
+            });
+            if !solved {
+                return;
+            }
+        }
+    }
+}
+
+fn finished(
+    rx: &Receiver<Message>,
+    timeout: Option<Duration>,
+) -> std::result::Result<Message, RecvTimeoutError> {
+    match timeout {
+        Some(timeout) => rx.recv_timeout(timeout),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    }
+}
+
+/// Parse `input` with the parameters in `overrides` and solve `part`, or
+/// both parts, with the main implementation and, when asked for, every
+/// alternate including the slow ones. Every phase is given `timeout`, no
+/// timeout waits as long as it takes.
+pub fn solve(
+    puzzle: &Puzzle,
+    input: &str,
+    overrides: &Overrides,
+    part: Option<Part>,
+    alternates: bool,
+    timeout: Option<Duration>,
+) -> Vec<Phase> {
+    let (tx, rx) = mpsc::channel();
+    let job = Job {
+        puzzle: *puzzle,
+        input: input.to_string(),
+        overrides: overrides.clone(),
+        part,
+        alternates,
+    };
+    std::thread::Builder::new()
+        .name(DayName(puzzle.day()).to_string())
+        .stack_size(STACK_SIZE)
+        .spawn(move || work(job, tx))
+        .expect("unable to spawn a worker thread");
+
+    let mut phases = vec![];
+    while let Ok(Message::Started(part, name, token)) = rx.recv() {
+        let started = Instant::now();
+        let unfinished = |outcome| Phase {
+            part,
+            name,
+            outcome,
+            elapsed: started.elapsed(),
+            alloc: None,
+        };
+        match finished(&rx, timeout) {
+            Ok(Message::Finished(phase)) => phases.push(phase),
+            Ok(Message::Started(..)) => unreachable!("phases run one at a time"),
+            Err(RecvTimeoutError::Timeout) => {
+                token.cancel();
+                phases.push(unfinished(Outcome::TimedOut));
+                // the cancelled phase gets another budget to notice
+                if !matches!(finished(&rx, timeout), Ok(Message::Finished(_))) {
+                    alloc::abandoned();
+                    break;
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                phases.push(unfinished(Outcome::Failed("panicked".to_string())));
+                break;
+            }
+        }
+    }
+    phases
+}
+
+#[cfg(test)]
+struct Spin;
+
+#[cfg(test)]
+impl aoc_common::Solution for Spin {
+    const DAY: u8 = 1;
+
+    type Input = u64;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().parse()?)
+    }
+
+    /// Spin until cancelled, unless asked to stop by itself.
+    fn part1(&n: &Self::Input) -> Result<Answer> {
+        let token = cancel::current();
+        let mut spins = 0u64;
+        while spins != n {
+            token.check()?;
+            spins += 1;
+        }
+        Ok(spins.into())
+    }
+
+    fn part2(&n: &Self::Input) -> Result<Answer> {
+        Ok((n as u128 + 1).into())
+    }
+}
+
+#[test]
+fn cancel_timed_out_parts() {
+    let puzzle = Puzzle::of::<Spin>();
+    let outcomes = |input: &str, timeout| -> Vec<_> {
+        solve(&puzzle, input, &Overrides::new(), None, false, timeout)
+            .into_iter()
+            .map(|phase| (phase.part, phase.outcome))
+            .collect()
+    };
+    let timeout = Some(Duration::from_millis(50));
+
+    assert_eq!(
+        outcomes("1000", timeout),
+        [
+            (None, Outcome::Parsed),
+            (Some(Part::One), Outcome::Solved(1000u64.into())),
+            (Some(Part::Two), Outcome::Solved(1001u64.into())),
+        ]
+    );
+    // part 1 spins forever and is cancelled, part 2 still runs
+    assert_eq!(
+        outcomes(&u64::MAX.to_string(), timeout),
+        [
+            (None, Outcome::Parsed),
+            (Some(Part::One), Outcome::TimedOut),
+            (Some(Part::Two), Outcome::Solved(Answer::Number(1 << 64))),
+        ]
+    );
+    assert_eq!(
+        outcomes("x", None)[..],
+        [(
+            None,
+            Outcome::Failed("invalid digit found in string".to_string())
+        )]
+    );
+}
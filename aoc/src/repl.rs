@@ -0,0 +1,166 @@
+//! Asking questions about a parsed input, see [`aoc_common::Query`].
+//!
+//! The input is parsed once, then every line is a query of the day, `part1`
+//! or `part2` to solve a part, `help` to list the commands or `quit`. Command
+//! names complete with Tab and the history is kept in `.repl_history` in the
+//! workspace root.
+
+use std::path::PathBuf;
+
+use aoc_common::params::Overrides;
+use aoc_common::{Parsed, Part, Puzzle, err, timed, workspace_root};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+use crate::{DayName, Result};
+
+/// Commands of every day, by name, usage and help.
+const BUILTINS: [(&str, &str, &str); 4] = [
+    ("part1", "", "solve part 1"),
+    ("part2", "", "solve part 2"),
+    ("help", "", "list the commands"),
+    ("quit", "", "leave, like Ctrl-D"),
+];
+
+/// Completes the command names.
+struct Commands(Vec<&'static str>);
+
+impl Completer for Commands {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let typed = &line[..pos];
+        if typed.contains(char::is_whitespace) {
+            return Ok((pos, vec![]));
+        }
+        let names = self.0.iter().filter(|name| name.starts_with(typed));
+        Ok((0, names.map(|name| name.to_string()).collect()))
+    }
+}
+
+impl Hinter for Commands {
+    type Hint = String;
+}
+
+impl Highlighter for Commands {}
+
+impl Validator for Commands {}
+
+impl Helper for Commands {}
+
+fn history_path() -> PathBuf {
+    workspace_root().join(".repl_history")
+}
+
+fn help(parsed: &dyn Parsed) -> String {
+    let commands: Vec<_> = parsed.queries().into_iter().chain(BUILTINS).collect();
+    let width = commands
+        .iter()
+        .map(|(name, usage, _)| name.len() + usage.len() + 1)
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<String> = commands
+        .iter()
+        .map(|(name, usage, help)| {
+            let command = format!("{name} {usage}");
+            format!("{command:<width$}  {help}")
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// The reply to a line, `None` to leave.
+fn eval(parsed: &dyn Parsed, line: &str) -> Option<Result<String>> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((&name, args)) = words.split_first() else {
+        return Some(Ok(String::new()));
+    };
+    let part = match name {
+        "part1" => Some(Part::One),
+        "part2" => Some(Part::Two),
+        _ => None,
+    };
+    Some(match (name, part) {
+        ("quit" | "exit", _) => return None,
+        ("help", _) => Ok(help(parsed)),
+        (_, Some(part)) if args.is_empty() => {
+            let (answer, elapsed) = timed(|| parsed.solve(part));
+            answer.map(|answer| format!("{answer}, took {elapsed:?}"))
+        }
+        _ => parsed
+            .query(name, args)
+            .unwrap_or_else(|| err!("unknown command `{name}`, see `help`")),
+    })
+}
+
+/// Answer the lines typed about `input` until `quit` or the end of input.
+pub fn repl(puzzle: &Puzzle, input: &str, overrides: &Overrides) -> Result<()> {
+    let (parsed, elapsed) = timed(|| puzzle.parse_with(input, overrides));
+    let parsed = parsed?;
+    let day = DayName(puzzle.day());
+    println!("{day} parsed in {elapsed:?}, `help` lists the commands");
+
+    let names = parsed
+        .queries()
+        .into_iter()
+        .chain(BUILTINS)
+        .map(|(name, _, _)| name)
+        .collect();
+    let mut editor = Editor::<Commands, DefaultHistory>::new()?;
+    editor.set_helper(Some(Commands(names)));
+    let history = history_path();
+    // there is no history the first time
+    let _ = editor.load_history(&history);
+
+    loop {
+        let line = match editor.readline(&format!("{day}> ")) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        if !line.trim().is_empty() {
+            editor.add_history_entry(line.as_str())?;
+        }
+        match eval(&*parsed, &line) {
+            Some(Ok(reply)) if reply.is_empty() => {}
+            Some(Ok(reply)) => println!("{reply}"),
+            Some(Err(e)) => eprintln!("{e}"),
+            None => break,
+        }
+    }
+    editor.save_history(&history)?;
+    Ok(())
+}
+
+#[test]
+fn eval_lines() -> Result<()> {
+    let puzzle = crate::days::find(5).unwrap();
+    let parsed = puzzle.parse("3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32")?;
+    let eval = |line| eval(&*parsed, line).map(|reply| reply.map_err(|e| e.to_string()));
+
+    assert_eq!(eval("  contains  12 ").unwrap()?, "12 is fresh, in 10-20");
+    assert!(eval("part2").unwrap()?.starts_with("14, took "));
+    assert_eq!(eval("").unwrap()?, "");
+    assert!(eval("help").unwrap()?.contains("contains ID"));
+    assert_eq!(
+        eval("contains").unwrap(),
+        Err("usage: contains ID".to_string())
+    );
+    assert_eq!(
+        eval("frobnicate 1").unwrap(),
+        Err("unknown command `frobnicate`, see `help`".to_string())
+    );
+    assert!(eval("quit").is_none());
+    Ok(())
+}
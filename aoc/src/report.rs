@@ -0,0 +1,272 @@
+//! Machine-readable reports of a run, for loading into dashboards.
+
+use std::process::Command;
+use std::time::Duration;
+
+use aoc_common::params::Overrides;
+use aoc_common::registry::input_hash;
+use aoc_common::{Answer, Puzzle, Result, err};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::alloc::AllocStats;
+use crate::cache::{self, Cache};
+use crate::phases::{self, Outcome};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// `git describe --always --dirty` of the workspace.
+    pub git_rev: Option<String>,
+    pub cores: usize,
+    /// Seconds since the Unix epoch.
+    pub created: u64,
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub input_hash: Option<String>,
+    /// Parameters replacing the puzzle constants, only in JSON.
+    #[serde(skip_serializing_if = "Overrides::is_empty")]
+    pub params: Overrides,
+    pub parse_ns: Option<u128>,
+    /// Only with the `alloc-stats` feature, see [`crate::alloc`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<AllocStats>,
+    pub parts: Vec<PartReport>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Status {
+    #[serde(rename = "solved")]
+    Solved,
+    #[serde(rename = "timed out")]
+    TimedOut,
+    /// Taken from the cache, with the duration of the run that solved it.
+    #[serde(rename = "cached")]
+    Cached,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::TimedOut => "timed out",
+            Status::Cached => "cached",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub status: Status,
+    /// `None` when the part timed out.
+    pub answer: Option<String>,
+    pub duration_ns: u128,
+    /// Only with the `alloc-stats` feature, see [`crate::alloc`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+}
+
+/// `git describe --always --dirty` of the workspace, when it is a checkout.
+pub fn git_rev() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .current_dir(aoc_common::workspace_root())
+        .output()
+        .ok()?;
+    let rev = String::from_utf8(output.stdout).ok()?;
+    output.status.success().then(|| rev.trim().to_string())
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self {
+            git_rev: git_rev(),
+            cores: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            created: crate::submit::now(),
+            days: vec![],
+        }
+    }
+
+    /// Solve `input` with the parameters in `overrides`, giving up on parts
+    /// running longer than `timeout`, or record why there is nothing to solve.
+    /// Parts in `cache` are taken from it.
+    pub fn add(
+        &mut self,
+        puzzle: &Puzzle,
+        input: Result<String>,
+        overrides: &Overrides,
+        timeout: Option<Duration>,
+        cache: Option<&mut Cache>,
+    ) {
+        let mut report = DayReport {
+            day: puzzle.day(),
+            input_hash: None,
+            params: overrides.clone(),
+            parse_ns: None,
+            parse_alloc: None,
+            parts: vec![],
+            error: None,
+        };
+        let measured = input.and_then(|input| {
+            report.input_hash = Some(input_hash(&input));
+            let phases = match cache {
+                Some(cache) => cache::solve(cache, puzzle, &input, overrides, None, timeout),
+                None => phases::solve(puzzle, &input, overrides, None, false, timeout),
+            };
+            for phase in phases {
+                phase.parse_error()?;
+                let (answer, status) = match phase.outcome {
+                    Outcome::Parsed => {
+                        report.parse_ns = Some(phase.elapsed.as_nanos());
+                        report.parse_alloc = phase.alloc;
+                        continue;
+                    }
+                    Outcome::Solved(Answer::Unsolved) => continue,
+                    Outcome::Solved(answer) => (Some(answer.to_string()), Status::Solved),
+                    Outcome::Cached(answer) => (Some(answer), Status::Cached),
+                    Outcome::TimedOut => (None, Status::TimedOut),
+                    Outcome::Failed(e) => return err!("part {}: {e}", phase.part.unwrap()),
+                };
+                report.parts.push(PartReport {
+                    part: phase.part.expect("parts are solved after parsing").into(),
+                    status,
+                    answer,
+                    duration_ns: phase.elapsed.as_nanos(),
+                    alloc: phase.alloc,
+                });
+            }
+            Ok(())
+        });
+        if let Err(e) = measured {
+            report.error = Some(e.to_string());
+        }
+        self.days.push(report);
+    }
+
+    pub fn render(&self, format: Format) -> Result<String> {
+        match format {
+            Format::Json => Ok(serde_json::to_string_pretty(self)? + "\n"),
+            Format::Csv => Ok(self.csv()),
+        }
+    }
+
+    /// One row per phase: `parse`, `part1`, `part2`, or `error` for a day
+    /// that could not be run. The allocation columns are empty without the
+    /// `alloc-stats` feature, and for every phase after one that was given up
+    /// still running after a timeout, which could still be allocating.
+    fn csv(&self) -> String {
+        let git_rev = self.git_rev.as_deref().unwrap_or("");
+        let mut csv = String::from(
+            "day,input_hash,phase,status,answer,duration_ns,allocations,alloc_bytes,peak_bytes,git_rev,cores\n",
+        );
+        let mut row = |day: &DayReport,
+                       phase: &str,
+                       status: &str,
+                       answer: &str,
+                       duration: Option<u128>,
+                       alloc: Option<AllocStats>| {
+            let alloc = |f: fn(AllocStats) -> u64| alloc.map(|a| f(a).to_string());
+            let fields = [
+                day.day.to_string(),
+                day.input_hash.clone().unwrap_or_default(),
+                phase.to_string(),
+                status.to_string(),
+                answer.to_string(),
+                duration.map(|d| d.to_string()).unwrap_or_default(),
+                alloc(|a| a.allocations).unwrap_or_default(),
+                alloc(|a| a.bytes).unwrap_or_default(),
+                alloc(|a| a.peak_bytes).unwrap_or_default(),
+                git_rev.to_string(),
+                self.cores.to_string(),
+            ];
+            let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        };
+        for day in &self.days {
+            if let Some(error) = &day.error {
+                row(day, "error", "error", error, None, None);
+                continue;
+            }
+            row(day, "parse", "ok", "", day.parse_ns, day.parse_alloc);
+            for part in &day.parts {
+                row(
+                    day,
+                    &format!("part{}", part.part),
+                    part.status.as_str(),
+                    part.answer.as_deref().unwrap_or(""),
+                    Some(part.duration_ns),
+                    part.alloc,
+                );
+            }
+        }
+        csv
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[test]
+fn report_formats() -> Result<()> {
+    let mut report = Report {
+        git_rev: Some("c704c0d".to_string()),
+        cores: 8,
+        created: 1764565200,
+        days: vec![],
+    };
+    report.add(
+        crate::days::find(1).unwrap(),
+        Ok("L68\nL30\nR48\n".to_string()),
+        &Overrides::new(),
+        None,
+        None,
+    );
+    report.add(
+        crate::days::find(2).unwrap(),
+        Err("unable to read aoc02/input/input.txt, \"missing\"".into()),
+        &Overrides::new(),
+        None,
+        None,
+    );
+
+    let csv = report.render(Format::Csv)?;
+    let rows: Vec<&str> = csv.lines().collect();
+    assert_eq!(rows.len(), 5);
+    assert_eq!(
+        rows[0],
+        "day,input_hash,phase,status,answer,duration_ns,allocations,alloc_bytes,peak_bytes,git_rev,cores"
+    );
+    assert!(rows[1].starts_with(&format!("1,{},parse,ok,,", input_hash("L68\nL30\nR48"))));
+    assert!(rows[2].contains(",part1,solved,1,"));
+    assert!(rows[3].contains(",part2,solved,2,") && rows[3].ends_with(",c704c0d,8"));
+    assert_eq!(
+        rows[4],
+        "2,,error,error,\"unable to read aoc02/input/input.txt, \"\"missing\"\"\",,,,,c704c0d,8"
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&report.render(Format::Json)?)?;
+    assert_eq!(json["cores"], 8);
+    assert_eq!(json["days"][0]["parts"][1]["part"], 2);
+    assert_eq!(json["days"][0]["parts"][1]["answer"], "2");
+    assert_eq!(json["days"][0]["parts"][1]["status"], "solved");
+    assert!(json["days"][0]["parse_ns"].is_u64());
+    assert!(json["days"][1]["error"].is_string());
+    Ok(())
+}
@@ -0,0 +1,381 @@
+//! Answer submission with a local history, so a known-wrong answer is never
+//! sent twice and the cooldown imposed by the server is respected.
+//!
+//! The history lives in `submissions.toml` in the workspace root (or wherever
+//! `AOC_SUBMISSIONS` points):
+//!
+//! ```toml
+//! [aoc01]
+//! wait_until = 1764565260
+//!
+//! [[aoc01.part1]]
+//! answer = "1200"
+//! outcome = "too-high"
+//! at = 1764565200
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::{Answer, Part, err, workspace_root};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::{DayName, Result};
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    AlreadySolved,
+    /// Answered too recently, nothing was checked.
+    Wait {
+        seconds: u64,
+    },
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Wait { seconds } => write!(f, "wait {seconds}s"),
+        }
+    }
+}
+
+/// A classified response page.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// Cooldown before the next answer may be submitted.
+    pub wait: Option<u64>,
+}
+
+impl Response {
+    pub fn parse(page: &str) -> Result<Self> {
+        let outcome = if page.contains("not the right answer") {
+            if page.contains("too high") {
+                Outcome::TooHigh
+            } else if page.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if page.contains("the right answer") {
+            Outcome::Correct
+        } else if page.contains("solving the right level") {
+            Outcome::AlreadySolved
+        } else if page.contains("answer too recently") {
+            Outcome::Wait {
+                seconds: left_to_wait(page).unwrap_or(60),
+            }
+        } else {
+            return err!("unrecognised response page");
+        };
+        let wait = match outcome {
+            Outcome::Wait { seconds } => Some(seconds),
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect => {
+                Some(wait_before_retry(page).unwrap_or(60))
+            }
+            Outcome::Correct | Outcome::AlreadySolved => None,
+        };
+        Ok(Self { outcome, wait })
+    }
+}
+
+/// Seconds in "You have 1m 23s left to wait".
+fn left_to_wait(page: &str) -> Option<u64> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+    left.split_whitespace().try_fold(0, |seconds, t| {
+        if let Some(m) = t.strip_suffix('m') {
+            Some(seconds + m.parse::<u64>().ok()? * 60)
+        } else {
+            Some(seconds + t.strip_suffix('s')?.parse::<u64>().ok()?)
+        }
+    })
+}
+
+/// Seconds in "Please wait one minute before trying again" or "wait 5 minutes".
+fn wait_before_retry(page: &str) -> Option<u64> {
+    let (_, rest) = page.split_once("wait ")?;
+    let minutes = match rest.split_whitespace().next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    Some(minutes * 60)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Submission {
+    answer: String,
+    outcome: Outcome,
+    at: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DayHistory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Submission>,
+}
+
+impl DayHistory {
+    fn part(&self, part: Part) -> &[Submission] {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut Vec<Submission> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+pub struct History {
+    path: PathBuf,
+    days: BTreeMap<String, DayHistory>,
+}
+
+impl History {
+    /// `$AOC_SUBMISSIONS`, or `submissions.toml` in the workspace root.
+    pub fn default_path() -> PathBuf {
+        std::env::var_os("AOC_SUBMISSIONS")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_root().join("submissions.toml"))
+    }
+
+    /// Load the history at `path`, a missing file is an empty history.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let days = match std::fs::read_to_string(&path) {
+            Ok(s) => toml::from_str(&s).map_err(|e| format!("{}: {e}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return err!("unable to read {}: {e}", path.display()),
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> Result<()> {
+        std::fs::write(&self.path, toml::to_string(&self.days)?)?;
+        Ok(())
+    }
+
+    /// Refuse answers that are known to be wrong, or known to be out of the
+    /// bounds given by earlier "too high" and "too low" responses, parts that
+    /// are already solved, and anything during a cooldown.
+    pub fn check(&self, day: u8, part: Part, answer: &Answer, now: u64) -> Result<()> {
+        let Some(history) = self.days.get(&DayName(day).to_string()) else {
+            return Ok(());
+        };
+        if let Some(until) = history.wait_until.filter(|&until| until > now) {
+            return err!("cooling down, try again in {}s", until - now);
+        }
+        let answer = answer.to_string();
+        let number = answer.parse::<u128>().ok();
+        for submission in history.part(part) {
+            let known = submission.answer.parse::<u128>().ok();
+            let refused = match submission.outcome {
+                Outcome::Correct | Outcome::AlreadySolved => true,
+                Outcome::TooHigh => number.zip(known).is_some_and(|(n, k)| n >= k),
+                Outcome::TooLow => number.zip(known).is_some_and(|(n, k)| n <= k),
+                Outcome::Incorrect | Outcome::Wait { .. } => false,
+            };
+            if refused || submission.answer == answer {
+                return err!(
+                    "part {part} already got {}: {}",
+                    submission.answer,
+                    submission.outcome
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer, response: &Response, now: u64) {
+        let history = self.days.entry(DayName(day).to_string()).or_default();
+        history.wait_until = response.wait.map(|wait| now + wait);
+        if !matches!(response.outcome, Outcome::Wait { .. }) {
+            history.part_mut(part).push(Submission {
+                answer: answer.to_string(),
+                outcome: response.outcome,
+                at: now,
+            });
+        }
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Submit `answer` unless the history refuses it, and record the outcome. The
+/// client is only built when the answer is actually sent.
+pub fn submit(
+    history: &mut History,
+    client: impl FnOnce() -> Result<Client>,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+    now: u64,
+) -> Result<Outcome> {
+    if *answer == Answer::Unsolved {
+        return err!("part {part} is not solved");
+    }
+    history.check(day, part, answer, now)?;
+    let response = Response::parse(&client()?.submit(day, part, answer)?)?;
+    history.record(day, part, answer, &response, now);
+    Ok(response.outcome)
+}
+
+#[test]
+fn classify_responses() -> Result<()> {
+    let page = "<article><p>That's not the right answer; your answer is too high.  If you're \
+                stuck, make sure you're using the full input data. Please wait one minute \
+                before trying again. <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article>";
+    assert_eq!(
+        Response::parse(page)?,
+        Response {
+            outcome: Outcome::TooHigh,
+            wait: Some(60)
+        }
+    );
+    let page = "<p>That's not the right answer.  Please wait 5 minutes before trying again.</p>";
+    assert_eq!(
+        Response::parse(page)?,
+        Response {
+            outcome: Outcome::Incorrect,
+            wait: Some(300)
+        }
+    );
+    let page = "<p>You gave an answer too recently; you have to wait after submitting an answer \
+                before trying again.  You have 1m 23s left to wait.</p>";
+    assert_eq!(
+        Response::parse(page)?,
+        Response {
+            outcome: Outcome::Wait { seconds: 83 },
+            wait: Some(83)
+        }
+    );
+    let page = "<p>That's the right answer!  You are one gold star closer.</p>";
+    assert_eq!(Response::parse(page)?.outcome, Outcome::Correct);
+    let page = "<p>You don't seem to be solving the right level.  Did you already complete it?</p>";
+    assert_eq!(Response::parse(page)?.outcome, Outcome::AlreadySolved);
+    assert!(Response::parse("<p>Gateway timeout</p>").is_err());
+    Ok(())
+}
+
+#[test]
+fn submit_with_history() -> Result<()> {
+    use crate::client::{mock_server, response};
+
+    let path = std::env::temp_dir().join(format!("aoc-submissions-{}.toml", std::process::id()));
+    let mut history = History::load(&path)?;
+    let (url, server) = mock_server(vec![
+        response(
+            "200 OK",
+            "<p>That's not the right answer; your answer is too high. Please wait one minute \
+             before trying again.</p>",
+        ),
+        response("200 OK", "<p>That's the right answer!</p>"),
+    ]);
+    let client = || Ok(Client::new(url.clone(), "abc123"));
+    let offline = || -> Result<Client> { panic!("refused answer was sent") };
+
+    let outcome = submit(
+        &mut history,
+        client,
+        1,
+        Part::One,
+        &Answer::from(1200u32),
+        1000,
+    )?;
+    assert_eq!(outcome, Outcome::TooHigh);
+    history.save()?;
+    let mut history = History::load(&path)?;
+    std::fs::remove_file(&path)?;
+
+    // cooling down, then known wrong, then out of bounds
+    assert!(
+        submit(
+            &mut history,
+            offline,
+            1,
+            Part::One,
+            &Answer::from(1100u32),
+            1030
+        )
+        .is_err()
+    );
+    assert!(
+        submit(
+            &mut history,
+            offline,
+            1,
+            Part::One,
+            &Answer::from(1200u32),
+            1100
+        )
+        .is_err()
+    );
+    assert!(
+        submit(
+            &mut history,
+            offline,
+            1,
+            Part::One,
+            &Answer::from(1300u32),
+            1100
+        )
+        .is_err()
+    );
+
+    let outcome = submit(
+        &mut history,
+        client,
+        1,
+        Part::One,
+        &Answer::from(1100u32),
+        1100,
+    )?;
+    assert_eq!(outcome, Outcome::Correct);
+    assert!(
+        submit(
+            &mut history,
+            offline,
+            1,
+            Part::One,
+            &Answer::from(1000u32),
+            1200
+        )
+        .is_err()
+    );
+    assert!(
+        history
+            .check(1, Part::Two, &Answer::from(1u32), 1200)
+            .is_ok()
+    );
+
+    let requests = server.join().unwrap();
+    assert!(requests[1].ends_with("level=1&answer=1100"));
+    Ok(())
+}
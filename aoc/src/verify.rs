@@ -0,0 +1,33 @@
+use aoc_common::registry::{Registry, Verdict, input_hash};
+use aoc_common::{Answer, Part, Puzzle};
+
+use crate::{DayName, Result};
+
+/// Solve both parts of `input` and compare them with the registry, printing
+/// one line per part. With `record`, unknown answers are added to the
+/// registry. Returns whether no part mismatched.
+pub fn verify(puzzle: &Puzzle, input: &str, registry: &mut Registry, record: bool) -> Result<bool> {
+    let hash = input_hash(input);
+    let parsed = puzzle.parse(input)?;
+    println!("== {} (input {hash})", DayName(puzzle.day()));
+
+    let mut ok = true;
+    for part in Part::ALL {
+        let answer = parsed.solve(part)?;
+        if answer == Answer::Unsolved {
+            continue;
+        }
+        let verdict = registry.check(puzzle.day(), &hash, part, &answer);
+        match verdict {
+            Verdict::Unknown if record => {
+                registry.set(puzzle.day(), &hash, part, &answer);
+                println!("part {part}: {answer} recorded");
+                continue;
+            }
+            Verdict::Mismatch { .. } => ok = false,
+            _ => {}
+        }
+        println!("part {part}: {answer} {verdict}");
+    }
+    Ok(ok)
+}
@@ -0,0 +1,13 @@
+[package]
+name = "{{name}}"
+version = "0.1.0"
+edition = "2024"
+
+[package.metadata.aoc]
+template = {{version}}
+
+[dependencies]
+aoc-common = { path = "../aoc-common" }
+
+[build-dependencies]
+aoc-common = { path = "../aoc-common" }
@@ -0,0 +1,7 @@
+# Expected answers of the fixtures next to this file, e.g. for `example-1.txt`:
+#
+# [example-1]
+# part1 = "3"
+# part2 = "6"
+#
+# `aoc examples {{day}} <page.html>` fills this in from a saved puzzle page.
@@ -0,0 +1,60 @@
+//! Day {{day}}.
+
+#![warn(missing_docs)]
+
+#[allow(unused_imports)]
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution, err, input};
+
+/// Parse one line per item.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Vec<String>> {
+    let input = input.as_ref();
+    Ok(input::lines(input).map(|l| l.to_string()).collect())
+}
+
+/// Answer of part 1.
+pub fn part1(_lines: &[String]) -> Result<Answer> {
+    Ok(Answer::Unsolved)
+}
+
+/// Answer of part 2.
+pub fn part2(_lines: &[String]) -> Result<Answer> {
+    Ok(Answer::Unsolved)
+}
+
+/// The solution of day {{day}} for the runner.
+pub struct {{Day}};
+
+impl Solution for {{Day}} {
+    const DAY: u8 = {{day}};
+
+    type Input = Vec<String>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        part1(lines)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        part2(lines)
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "";
+    let lines = parse_input(input)?;
+    assert_eq!(part1(&lines)?, Answer::Unsolved);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input({{Day}}::DAY)?;
+    aoc_common::registry::verify::<{{Day}}>(&input)
+}
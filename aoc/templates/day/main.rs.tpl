@@ -0,0 +1,6 @@
+use aoc_common::Result;
+use {{name}}::{{Day}};
+
+fn main() -> Result<()> {
+    aoc_common::main::<{{Day}}>()
+}
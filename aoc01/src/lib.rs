@@ -0,0 +1,148 @@
+//! Day 1: Secret Entrance, counting how often a safe dial lands on or passes
+//! mark 0.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution, err, input};
+use serde::{Deserialize, Serialize};
+
+/// The dial of the safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Params {
+    /// Number of marks on the dial.
+    pub size: i32,
+    /// Mark the dial points at before the first rotation.
+    pub start: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            size: 100,
+            start: 50,
+        }
+    }
+}
+
+/// Parse one rotation per line, `L` as negative and `R` as positive distance.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Vec<i32>> {
+    let input = input.as_ref();
+    input::lines(input)
+        .map(|l| {
+            if let Some(n) = l.strip_prefix("R") {
+                input::parse::<i32>(n, "a rotation distance")
+            } else if let Some(n) = l.strip_prefix("L") {
+                input::parse::<i32>(n, "a rotation distance").map(|val| -val)
+            } else {
+                Err(ParseError::new(l, "a rotation like `L68` or `R48`"))
+            }
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))
+}
+
+/// Rotations that leave the dial at mark 0.
+pub fn part1(rotations: &[i32], params: &Params) -> Result<u32> {
+    let mut dial = params.start;
+    let mut password = 0;
+
+    for rot in rotations {
+        dial = (dial + rot).rem_euclid(params.size);
+        password += (dial == 0) as u32;
+    }
+
+    Ok(password)
+}
+
+/// Times the dial points at mark 0, during a rotation or after it.
+pub fn part2(rotations: &[i32], params: &Params) -> Result<u32> {
+    let size = params.size;
+    let mut dial = params.start;
+    let mut password = 0;
+
+    for rot in rotations {
+        // 计算一定会经过 0 刻度的整圈次数
+        password += rot.unsigned_abs() / size as u32;
+
+        // 扣除整圈的旋转距离，
+        // 可以规避例如从 0 刻度旋转距离 100 的边界情况
+        let rot = rot % size;
+
+        let temp = dial + rot;
+        // 当起点不是 0 刻度时，
+        // 向左或向右旋转超过或位于刻度边界 0 或 100 时，
+        // 一定经过一次 0 刻度
+        password += (dial != 0 && (temp >= size || temp <= 0)) as u32;
+
+        // 计算旋转后刻度的真实位置，刻度值一定大于 0
+        dial = temp.rem_euclid(size);
+    }
+
+    Ok(password)
+}
+
+/// The solution of day 1 for the runner.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i32>, Params);
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        if params.size <= 0 || !(0..params.size).contains(&params.start) {
+            return err!("the dial starts at a mark between 0 and its size");
+        }
+        Ok((parse_input(input)?, *params))
+    }
+
+    fn part1((rotations, params): &Self::Input) -> Result<Answer> {
+        part1(rotations, params).map(Answer::from)
+    }
+
+    fn part2((rotations, params): &Self::Input) -> Result<Answer> {
+        part2(rotations, params).map(Answer::from)
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+    let rotations = parse_input(input)?;
+    let params = Params::default();
+    assert_eq!(part1(&rotations, &params).unwrap(), 3);
+    assert_eq!(part2(&rotations, &params).unwrap(), 6);
+    let params = Params { size: 10, start: 5 };
+    assert_eq!(part1(&rotations, &params).unwrap(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day01::DAY)?;
+    aoc_common::registry::verify::<Day01>(&input)
+}
+
+#[test]
+fn parse_error() {
+    let e = parse_input("L68\nL30\nX48\n").unwrap_err();
+    assert_eq!((e.line(), e.column(), e.token()), (3, 1, "X48"));
+    let e = parse_input("L68\n  R4x8\n").unwrap_err();
+    assert_eq!((e.line(), e.column(), e.token()), (2, 4, "4x8"));
+}
@@ -0,0 +1,258 @@
+//! Day 2: Gift Shop, summing the invalid product ids made of a repeated
+//! sequence of digits.
+
+#![warn(missing_docs)]
+
+use std::collections::HashSet;
+
+use aoc_common::fuzz::Rng;
+use aoc_common::{Alternate, Answer, ParseError, ParseResult, Part, Result, Solution, input};
+
+/// Parse comma separated inclusive id ranges `start-end`.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Vec<(usize, usize)>> {
+    let input = input.as_ref();
+    input
+        .split(",")
+        .map(|rg| {
+            let rg = rg.trim();
+            if let Some((start, end)) = rg.split_once("-") {
+                let start: usize = input::parse(start.trim(), "a positive id")?;
+                let end: usize = input::parse(end.trim(), "a positive id")?;
+                if start == 0 || start > end {
+                    return Err(ParseError::new(
+                        rg,
+                        "a range `start-end` with 0 < start <= end",
+                    ));
+                }
+                Ok((start, end))
+            } else {
+                Err(ParseError::new(rg, "a range like `11-22`"))
+            }
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))
+}
+
+/// Sum of the ids made of a sequence repeated twice, checking every id.
+pub fn part1(ranges: &[(usize, usize)]) -> Result<usize> {
+    let mut ids = 0;
+
+    for &(start, end) in ranges {
+        for n in start..=end {
+            let l = n.ilog10() + 1;
+            if l % 2 == 0 {
+                let base = 10usize.pow(l / 2);
+                let right = n % base;
+                let left = n / base;
+                if left == right {
+                    ids += n;
+                }
+            }
+        }
+    }
+
+    Ok(ids)
+}
+
+/// Sum of the ids made of a sequence repeated at least twice, checking
+/// every id.
+pub fn part2(ranges: &[(usize, usize)]) -> Result<usize> {
+    let mut ids = 0;
+
+    for &(start, end) in ranges {
+        for n in start..=end {
+            let l = n.ilog10() + 1;
+            'check_base: for base in 1..=l / 2 {
+                if l % base == 0 {
+                    let base = 10usize.pow(base);
+
+                    let right = n % base;
+                    let mut temp = n / base;
+                    while temp != 0 {
+                        if temp % base != right {
+                            continue 'check_base;
+                        }
+                        temp /= base;
+                    }
+                    ids += n;
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(ids)
+}
+
+fn split_range(start: usize, end: usize) -> Vec<(usize, usize)> {
+    let (start_l, end_l) = (start.ilog10(), end.ilog10());
+    if start_l < end_l {
+        let mut ranges = vec![];
+        let mut start = start;
+        for i in start_l..=end_l {
+            let new_end = 10usize.pow(i + 1) - 1;
+            ranges.push((start, new_end.min(end)));
+            start = new_end + 1;
+        }
+        ranges
+    } else {
+        vec![(start, end)]
+    }
+}
+
+fn find_invalid(start: usize, end: usize, base: u32) -> Vec<usize> {
+    assert_eq!(start.ilog10(), end.ilog10());
+    let l = start.ilog10() + 1;
+    if !l.is_multiple_of(base) {
+        return vec![];
+    }
+    let (start_left, end_left) = (start / 10usize.pow(l - base), end / 10usize.pow(l - base));
+    let mut invalids = Vec::new();
+    for s in start_left..=end_left {
+        let n = (0..l)
+            .step_by(base as usize)
+            .fold(0, |n, i| n + s * 10usize.pow(i));
+        if start <= n && n <= end {
+            invalids.push(n);
+        }
+    }
+    invalids
+}
+
+/// [`part1`] generating the invalid ids from their first half instead.
+pub fn part1_step(ranges: &[(usize, usize)]) -> Result<usize> {
+    let mut ids = 0;
+
+    for &(start, end) in ranges {
+        for (start, end) in split_range(start, end) {
+            let l = start.ilog10() + 1;
+            if l % 2 == 0 {
+                ids += find_invalid(start, end, l / 2).iter().sum::<usize>()
+            }
+        }
+    }
+
+    Ok(ids)
+}
+
+/// [`part2`] generating the invalid ids from their repeated sequence
+/// instead.
+pub fn part2_step(ranges: &[(usize, usize)]) -> Result<usize> {
+    let mut ids = 0;
+
+    for &(start, end) in ranges {
+        // 同一个 id 可能由不同长度的序列重复得到，只在当前范围内去重，
+        // 和 part2 一样，重叠的范围各算一次
+        let mut invalids = HashSet::new();
+        for (start, end) in split_range(start, end) {
+            let l = start.ilog10() + 1;
+            for base in 1..=l / 2 {
+                if l % base == 0 {
+                    invalids.extend(find_invalid(start, end, base).iter());
+                }
+            }
+        }
+        ids += invalids.iter().sum::<usize>();
+    }
+
+    Ok(ids)
+}
+
+/// The solution of day 2 for the runner.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<(usize, usize)>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(ranges: &Self::Input) -> Result<Answer> {
+        part1(ranges).map(Answer::from)
+    }
+
+    fn part2(ranges: &Self::Input) -> Result<Answer> {
+        part2(ranges).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![
+            Alternate {
+                part: Part::One,
+                name: "step",
+                solve: |ranges| part1_step(ranges).map(Answer::from),
+                slow: false,
+            },
+            Alternate {
+                part: Part::Two,
+                name: "step",
+                solve: |ranges| part2_step(ranges).map(Answer::from),
+                slow: false,
+            },
+        ]
+    }
+
+    fn cases(input: &str) -> Vec<&str> {
+        input.split(',').map(|rg| rg.trim()).collect()
+    }
+
+    /// Ranges anywhere, overlapping ones included although the real input
+    /// has none, an id in several ranges counting once for each.
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let ranges: Vec<String> = (0..rng.index(1..=4))
+            .map(|_| {
+                let start = rng.range(1..=3000);
+                let end = start + rng.range(0..=300);
+                format!("{start}-{end}")
+            })
+            .collect();
+        Some(ranges.join(","))
+    }
+}
+
+#[test]
+fn split_range_test() -> Result<()> {
+    assert_eq!(split_range(90, 115), vec![(90, 99), (100, 115)]);
+    assert_eq!(split_range(103, 115), vec![(103, 115)]);
+    assert_eq!(
+        split_range(90, 1215),
+        vec![(90, 99), (100, 999), (1000, 1215)]
+    );
+    Ok(())
+}
+
+#[test]
+fn find_invalid_test() -> Result<()> {
+    assert_eq!(find_invalid(90, 99, 1), vec![99]);
+    assert_eq!(find_invalid(101, 120, 1), vec![111]);
+    assert_eq!(find_invalid(38593856, 38593862, 4), vec![38593859]);
+    assert_eq!(find_invalid(11, 22, 1), vec![11, 22]);
+    Ok(())
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,
+824824821-824824827,2121212118-2121212124";
+
+    let ranges = parse_input(input)?;
+    assert_eq!(part1(&ranges).unwrap(), 1227775554);
+    assert_eq!(part2(&ranges).unwrap(), 4174379265);
+
+    assert_eq!(part1_step(&ranges).unwrap(), 1227775554);
+    assert_eq!(part2_step(&ranges).unwrap(), 4174379265);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day02::DAY)?;
+    aoc_common::registry::verify::<Day02>(&input)
+}
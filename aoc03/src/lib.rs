@@ -0,0 +1,206 @@
+//! Day 3: Lobby, turning on the batteries of every bank that give the largest
+//! joltage.
+
+#![warn(missing_docs)]
+
+use aoc_common::fuzz::Rng;
+use aoc_common::{Alternate, Answer, ParseError, ParseResult, Part, Result, Solution, err, input};
+use serde::{Deserialize, Serialize};
+
+/// Most digits of a joltage that fits in a `usize`.
+const MAX_DIGITS: usize = usize::MAX.ilog10() as usize;
+
+/// Batteries turned on in every bank, forming the digits of its joltage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Params {
+    /// Digits in part 1.
+    pub part1_digits: usize,
+    /// Digits in part 2.
+    pub part2_digits: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_digits: 2,
+            part2_digits: 12,
+        }
+    }
+}
+
+/// Parse one bank per line, a digit per battery.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Vec<Vec<usize>>> {
+    let input = input.as_ref();
+    input::lines(input)
+        .map(|l| {
+            l.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as usize)
+                        .ok_or_else(|| ParseError::new(&l[i..i + c.len_utf8()], "a joltage digit"))
+                })
+                .collect::<ParseResult<Vec<_>>>()
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))
+}
+
+/// Largest joltage of `number` batteries of `battery`, kept in order,
+/// picking the largest digit that leaves enough batteries for the rest.
+pub fn find_largest_joltage(battery: &[usize], number: usize) -> usize {
+    let length = battery.len();
+    let mut joltage = 0;
+    let mut next_battery = 0;
+    for l in (0..number).rev() {
+        let mut max_battery = 0;
+        (next_battery..(length - l)).for_each(|left| {
+            if battery[left] > max_battery {
+                max_battery = battery[left];
+                next_battery = left + 1;
+            }
+        });
+        joltage = joltage * 10 + max_battery;
+    }
+    joltage
+}
+
+/// [`find_largest_joltage`] by dynamic programming over the suffixes of
+/// `battery`.
+pub fn dp_find_largest_joltage(battery: &[usize], number: usize) -> usize {
+    let length = battery.len();
+    let mut dp = vec![vec![0; length + 1]; number + 1];
+    let mut mul = 1;
+    for len in 1..=number {
+        let mut max = 0;
+        for (i, &b) in battery.iter().enumerate().take(length - len + 1).rev() {
+            max = max.max(b * mul + dp[len - 1][i + 1]);
+            dp[len][i] = max;
+        }
+        mul *= 10;
+    }
+    dp[number][0]
+}
+
+/// Total joltage of every bank with `digits` batteries on, the digits of
+/// part 1 or of part 2.
+pub fn part1(batteries: &[Vec<usize>], digits: usize) -> Result<usize> {
+    let joltage = batteries
+        .iter()
+        .map(|b| find_largest_joltage(b, digits))
+        .sum();
+
+    Ok(joltage)
+}
+
+/// [`part1`] with [`dp_find_largest_joltage`].
+pub fn part2_dp(batteries: &[Vec<usize>], digits: usize) -> Result<usize> {
+    let joltage = batteries
+        .iter()
+        .map(|b| dp_find_largest_joltage(b, digits))
+        .sum();
+
+    Ok(joltage)
+}
+
+/// The solution of day 3 for the runner.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = (Vec<Vec<usize>>, Params);
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let batteries = parse_input(input)?;
+        let digits = params.part1_digits.max(params.part2_digits);
+        for digits in [params.part1_digits, params.part2_digits] {
+            if !(1..=MAX_DIGITS).contains(&digits) {
+                return err!("the joltage has 1 to {MAX_DIGITS} digits, not {digits}");
+            }
+        }
+        if let Some(i) = batteries.iter().position(|b| b.len() < digits) {
+            return err!("bank {} has fewer than {digits} batteries", i + 1);
+        }
+        Ok((batteries, *params))
+    }
+
+    fn part1((batteries, params): &Self::Input) -> Result<Answer> {
+        part1(batteries, params.part1_digits).map(Answer::from)
+    }
+
+    fn part2((batteries, params): &Self::Input) -> Result<Answer> {
+        part1(batteries, params.part2_digits).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![Alternate {
+            part: Part::Two,
+            name: "dp",
+            solve: |(batteries, params)| part2_dp(batteries, params.part2_digits).map(Answer::from),
+            slow: false,
+        }]
+    }
+
+    fn cases(input: &str) -> Vec<&str> {
+        input::lines(input).collect()
+    }
+
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let banks: Vec<String> = (0..rng.index(1..=4))
+            .map(|_| {
+                (0..rng.index(12..=20))
+                    .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                    .collect()
+            })
+            .collect();
+        Some(banks.join("\n"))
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+    let batteries = parse_input(input)?;
+    assert_eq!(part1(&batteries, 2).unwrap(), 357);
+    assert_eq!(part1(&batteries, 12).unwrap(), 3121910778619);
+    assert_eq!(part2_dp(&batteries, 12).unwrap(), 3121910778619);
+    assert_eq!(part1(&batteries, 3).unwrap(), 987 + 819 + 478 + 921);
+    Ok(())
+}
+
+#[test]
+fn digits_param() {
+    let bank = "1234567890123456789012345";
+    let parse = |part2_digits| {
+        let params = Params {
+            part2_digits,
+            ..Params::default()
+        };
+        Day03::parse_with(bank, &params).map_err(|e| e.to_string())
+    };
+    assert!(parse(19).is_ok());
+    assert_eq!(
+        parse(21).unwrap_err(),
+        "the joltage has 1 to 19 digits, not 21"
+    );
+    assert_eq!(
+        parse(0).unwrap_err(),
+        "the joltage has 1 to 19 digits, not 0"
+    );
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day03::DAY)?;
+    aoc_common::registry::verify::<Day03>(&input)
+}
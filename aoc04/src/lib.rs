@@ -0,0 +1,231 @@
+//! Day 4: Printing Department, finding the paper rolls forklifts can reach
+//! and how many can be removed one after another.
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::bitgrid::BitGrid;
+use aoc_common::fuzz::Rng;
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::{Alternate, Answer, ParseError, ParseResult, Part, Result, Solution};
+use serde::{Deserialize, Serialize};
+
+/// When a forklift can reach a roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Params {
+    /// A roll is accessible with fewer rolls than this around it.
+    pub threshold: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { threshold: 4 }
+    }
+}
+
+/// Parse the grid, `@` for a roll of paper and `.` for an empty place, as
+/// whether there is a roll.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Grid<bool>> {
+    let input = input.as_ref();
+    let grid = Grid::parse(input, "a roll `@` or an empty `.`", |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+    .map_err(|e| e.locate(input))?;
+    if grid.is_empty() {
+        return Err(ParseError::new(input, "a grid of paper rolls").locate(input));
+    }
+    Ok(grid)
+}
+
+/// Rolls among the eight neighbours of `pos`.
+fn adjacent_rolls(grid: &Grid<bool>, pos: Pos) -> usize {
+    grid.neighbours8(pos).filter(|&n| grid[n]).count()
+}
+
+/// Rolls with fewer than `threshold` rolls among their eight neighbours.
+pub fn part1(rolls: &BitGrid, threshold: usize) -> Result<usize> {
+    let count = rolls
+        .rows()
+        .zip(rolls.fewer_neighbours(threshold).rows())
+        .flat_map(|(rolls, fewer)| rolls.iter().zip(fewer))
+        .map(|(rolls, fewer)| (rolls & fewer).count_ones() as usize)
+        .sum();
+
+    Ok(count)
+}
+
+/// [`part1`] one cell at a time.
+pub fn part1_cells(grid: &Grid<bool>, threshold: usize) -> Result<usize> {
+    let count = grid
+        .iter()
+        .filter(|&(pos, &roll)| roll && adjacent_rolls(grid, pos) < threshold)
+        .count();
+
+    Ok(count)
+}
+
+/// Rolls removed by repeatedly removing the accessible ones.
+///
+/// Rows are swept over and over, removing the accessible rolls of a row 64
+/// at a time, and only the rows next to removed rolls are looked at again.
+/// Which rolls end up removed does not depend on the order.
+pub fn part2(rolls: &BitGrid, threshold: usize) -> Result<usize> {
+    let mut rolls = rolls.clone();
+    let mut fewer = vec![0; rolls.width().div_ceil(u64::BITS as usize)];
+    let mut dirty = vec![true; rolls.height()];
+    let mut count = 0;
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for row in 0..rolls.height() {
+            if !std::mem::take(&mut dirty[row]) {
+                continue;
+            }
+            rolls.fewer_neighbours_in_row(row, threshold, &mut fewer);
+            let mut removed = 0;
+            for (cells, fewer) in rolls.row_mut(row).iter_mut().zip(&fewer) {
+                let accessible = *cells & fewer;
+                removed += accessible.count_ones() as usize;
+                *cells &= !accessible;
+            }
+            if removed > 0 {
+                count += removed;
+                changed = true;
+                let next = (row + 2).min(rolls.height());
+                dirty[row.saturating_sub(1)..next].fill(true);
+            }
+        }
+    }
+
+    Ok(count)
+}
+
+/// [`part2`] one roll at a time, with a queue of the accessible ones.
+pub fn part2_queue(grid: &Grid<bool>, threshold: usize) -> Result<usize> {
+    let mut queue = VecDeque::new();
+    let mut adjacent_count = HashMap::new();
+
+    for (pos, &roll) in grid.iter() {
+        if roll {
+            let conn = adjacent_rolls(grid, pos);
+            if conn < threshold {
+                queue.push_back(pos);
+            }
+            adjacent_count.insert(pos, conn);
+        }
+    }
+
+    let mut removed = HashSet::new();
+
+    while let Some(p) = queue.pop_front() {
+        if removed.insert(p) {
+            for n in grid.neighbours8(p) {
+                if let Some(v) = adjacent_count.get_mut(&n) {
+                    *v -= 1;
+                    if *v < threshold {
+                        queue.push_back(n);
+                    }
+                }
+            }
+        }
+    }
+
+    let count = removed.len();
+
+    Ok(count)
+}
+
+/// The solution of day 4 for the runner.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    const VERSION: u32 = 2;
+
+    type Input = (BitGrid, Params);
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        Ok((BitGrid::from(&parse_input(input)?), *params))
+    }
+
+    fn part1((grid, params): &Self::Input) -> Result<Answer> {
+        part1(grid, params.threshold).map(Answer::from)
+    }
+
+    fn part2((grid, params): &Self::Input) -> Result<Answer> {
+        part2(grid, params.threshold).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![
+            Alternate {
+                part: Part::One,
+                name: "cells",
+                solve: |(rolls, params)| {
+                    part1_cells(&rolls.to_grid(), params.threshold).map(Answer::from)
+                },
+                slow: false,
+            },
+            Alternate {
+                part: Part::Two,
+                name: "queue",
+                solve: |(rolls, params)| {
+                    part2_queue(&rolls.to_grid(), params.threshold).map(Answer::from)
+                },
+                slow: false,
+            },
+        ]
+    }
+
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let width = rng.index(1..=100);
+        let rows: Vec<String> = (0..rng.index(1..=20))
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.chance(0.7) { '@' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        Some(rows.join("\n"))
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+    let grid = parse_input(input)?;
+    let rolls = BitGrid::from(&grid);
+    assert_eq!(part1(&rolls, 4).unwrap(), 13);
+    assert_eq!(part2(&rolls, 4).unwrap(), 43);
+    assert_eq!(part1_cells(&grid, 4).unwrap(), 13);
+    assert_eq!(part2_queue(&grid, 4).unwrap(), 43);
+    assert_eq!(part1(&rolls, 9).unwrap(), part2(&rolls, 9).unwrap());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day04::DAY)?;
+    aoc_common::registry::verify::<Day04>(&input)
+}
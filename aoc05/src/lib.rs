@@ -0,0 +1,223 @@
+//! Day 5: Cafeteria, checking ingredient ids against ranges of fresh ids.
+
+#![warn(missing_docs)]
+
+use std::cmp::Ordering::{Equal, Greater, Less};
+
+use aoc_common::{Answer, ParseError, ParseResult, Query, Result, Solution, input};
+
+/// Inclusive range of ingredient ids, `(start, end)`.
+pub type IdRange = (usize, usize);
+
+/// Parse the fresh id ranges, a blank line and the available ids.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<(Vec<IdRange>, Vec<usize>)> {
+    let input = input.as_ref();
+    let mut blocks = input::blocks(input);
+    if let (Some(ranges), Some(ids)) = (blocks.next(), blocks.next()) {
+        let ranges = input::lines(ranges)
+            .map(|l| {
+                if let Some((s, e)) = l.split_once("-") {
+                    let s: usize = input::parse(s, "an ingredient id")?;
+                    let e: usize = input::parse(e, "an ingredient id")?;
+                    if s > e {
+                        return Err(ParseError::new(l, "a range `start-end` with start <= end"));
+                    }
+                    Ok((s, e))
+                } else {
+                    Err(ParseError::new(l, "a fresh id range like `3-5`"))
+                }
+            })
+            .collect::<ParseResult<_>>();
+        let ids = input::lines(ids)
+            .map(|l| input::parse::<usize>(l, "an ingredient id"))
+            .collect::<ParseResult<_>>();
+        ranges
+            .and_then(|ranges| Ok((ranges, ids?)))
+            .map_err(|e| e.locate(input))
+    } else {
+        let rest = input.trim_end();
+        let e = ParseError::new(
+            &rest[rest.len()..],
+            "a blank line followed by ingredient ids",
+        );
+        Err(e.locate(input))
+    }
+}
+
+/// Available ids that are in a fresh range.
+pub fn part1(ranges: &[IdRange], ids: &[usize]) -> Result<usize> {
+    let ranges = merge_ranges(ranges);
+
+    // let count = ids
+    //     .iter()
+    //     .filter(|&&id| ranges.iter().any(|r| r.0 <= id && r.1 >= id))
+    //     .count();
+
+    let count = ids
+        .iter()
+        .filter(|&&id| {
+            ranges
+                .binary_search_by(|r| {
+                    if r.1 < id {
+                        Less
+                    } else if r.0 > id {
+                        Greater
+                    } else {
+                        Equal
+                    }
+                })
+                .is_ok()
+        })
+        .count();
+
+    Ok(count)
+}
+
+/// The union of two ranges when it is a single range, i.e. they overlap or
+/// touch.
+pub fn merge_range(r: IdRange, other: IdRange) -> Option<IdRange> {
+    let (r, other) = if r.0 > other.0 {
+        (other, r)
+    } else {
+        (r, other)
+    };
+    if r.1 + 1 < other.0 {
+        None
+    } else {
+        Some((r.0, r.1.max(other.1)))
+    }
+}
+
+/// Sorted disjoint ranges covering the same ids as `ranges`.
+///
+/// ```
+/// assert_eq!(
+///     aoc05::merge_ranges(&[(16, 20), (3, 5), (12, 18), (10, 14), (6, 6)]),
+///     [(3, 6), (10, 20)]
+/// );
+/// ```
+pub fn merge_ranges(ranges: &[IdRange]) -> Vec<IdRange> {
+    let mut ranges = ranges.to_vec();
+    ranges.sort();
+    let mut merged = vec![];
+
+    let Some((&first, rest)) = ranges.split_first() else {
+        return merged;
+    };
+    let mut current = first;
+
+    for &next in rest {
+        if let Some(m) = merge_range(current, next) {
+            current = m;
+        } else {
+            merged.push(current);
+            current = next;
+        }
+    }
+    merged.push(current);
+    merged
+}
+
+/// Ids in any fresh range.
+pub fn part2(ranges: &[IdRange]) -> Result<usize> {
+    let count = merge_ranges(ranges).iter().map(|(s, e)| e - s + 1).sum();
+
+    Ok(count)
+}
+
+/// The solution of day 5 for the runner.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<IdRange>, Vec<usize>);
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        part1(&input.0, &input.1).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        part2(&input.0).map(Answer::from)
+    }
+
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![
+            Query {
+                name: "contains",
+                usage: "ID",
+                help: "the merged fresh range holding an ingredient id",
+                run: |(ranges, _), args| {
+                    let id: usize = input::parse(args[0], "an ingredient id")?;
+                    let merged = merge_ranges(ranges);
+                    Ok(match merged.iter().find(|r| r.0 <= id && id <= r.1) {
+                        Some((s, e)) => format!("{id} is fresh, in {s}-{e}"),
+                        None => format!("{id} is not in any fresh range"),
+                    })
+                },
+            },
+            Query {
+                name: "ranges",
+                usage: "",
+                help: "the fresh ranges once merged",
+                run: |(ranges, _), _| {
+                    let merged: Vec<String> = merge_ranges(ranges)
+                        .iter()
+                        .map(|(s, e)| format!("{s}-{e}"))
+                        .collect();
+                    Ok(merged.join("\n"))
+                },
+            },
+        ]
+    }
+}
+
+#[test]
+fn test_merge() -> Result<()> {
+    assert_eq!(merge_range((10, 15), (18, 22)), None);
+    assert_eq!(merge_range((10, 19), (18, 22)), Some((10, 22)));
+    assert_eq!(merge_range((10, 19), (12, 18)), Some((10, 19)));
+    assert_eq!(merge_range((10, 19), (1, 15)), Some((1, 19)));
+    assert_eq!(merge_range((10, 19), (1, 8)), None);
+    assert_eq!(merge_range((10, 20), (12, 18)), Some((10, 20)));
+    Ok(())
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+    let (ranges, ids) = parse_input(input)?;
+    assert_eq!(part1(&ranges, &ids).unwrap(), 3);
+    assert_eq!(part2(&ranges).unwrap(), 14);
+
+    let parsed = aoc_common::Puzzle::of::<Day05>().parse(input)?;
+    let query = |name, args: &[&str]| parsed.query(name, args).unwrap();
+    assert_eq!(query("contains", &["17"])?, "17 is fresh, in 10-20");
+    assert_eq!(query("contains", &["8"])?, "8 is not in any fresh range");
+    assert_eq!(query("ranges", &[])?, "3-5\n10-20");
+    assert!(query("contains", &[]).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day05::DAY)?;
+    aoc_common::registry::verify::<Day05>(&input)
+}
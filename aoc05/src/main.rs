@@ -1,162 +1,6 @@
-use std::cmp::Ordering::{Equal, Greater, Less};
-use std::error::Error;
-use std::io::{self, Read};
-use std::time::Instant;
-
-#[allow(unused_macros)]
-macro_rules! err {
-    ($($tt:tt)*) => { Err(Box::<dyn Error>::from(format!($($tt)*))) }
-}
-
-type Result<T> = ::std::result::Result<T, Box<dyn Error>>;
-type IdRange = (usize, usize);
-
-fn parse_input<T: AsRef<str>>(input: T) -> Result<(Vec<IdRange>, Vec<usize>)> {
-    if let Some((ranges, ids)) = input.as_ref().trim().split_once("\n\n") {
-        let ranges = ranges
-            .trim()
-            .lines()
-            .filter(|l| !l.trim().is_empty())
-            .map(|l| {
-                if let Some((s, e)) = l.trim().split_once("-") {
-                    Ok((s.parse::<usize>()?, e.parse::<usize>()?))
-                } else {
-                    err!("unable to parse line: {l}")
-                }
-            })
-            .collect::<Result<_>>()?;
-        let ids = ids
-            .trim()
-            .lines()
-            .filter(|l| !l.trim().is_empty())
-            .map(|l| l.trim().parse::<usize>().map_err(|e| e.into()))
-            .collect::<Result<_>>()?;
-        Ok((ranges, ids))
-    } else {
-        err!("unable to parse input")
-    }
-}
-
-fn part1(ranges: &[IdRange], ids: &[usize]) -> Result<usize> {
-    let _start = Instant::now();
-
-    let ranges = merge_ranges(ranges);
-
-    // let count = ids
-    //     .iter()
-    //     .filter(|&&id| ranges.iter().any(|r| r.0 <= id && r.1 >= id))
-    //     .count();
-
-    let count = ids
-        .iter()
-        .filter(|&&id| {
-            ranges
-                .binary_search_by(|r| {
-                    if r.1 < id {
-                        Less
-                    } else if r.0 > id {
-                        Greater
-                    } else {
-                        Equal
-                    }
-                })
-                .is_ok()
-        })
-        .count();
-
-    println!("part 1: {count}");
-    println!("> Time elapsed is: {:?}", _start.elapsed());
-    Ok(count)
-}
-
-fn merge_range(r: IdRange, other: IdRange) -> Option<IdRange> {
-    let (r, other) = if r.0 > other.0 {
-        (other, r)
-    } else {
-        (r, other)
-    };
-    if r.1 + 1 < other.0 {
-        None
-    } else {
-        Some((r.0, r.1.max(other.1)))
-    }
-}
-
-fn merge_ranges(ranges: &[IdRange]) -> Vec<IdRange> {
-    let mut ranges = ranges.to_vec();
-    ranges.sort();
-    let mut merged = vec![];
-
-    let mut current = ranges[0];
-
-    for &next in &ranges[1..] {
-        if let Some(m) = merge_range(current, next) {
-            current = m;
-        } else {
-            merged.push(current);
-            current = next;
-        }
-    }
-    merged.push(current);
-    merged
-}
-
-fn part2(ranges: &[IdRange]) -> Result<usize> {
-    let _start = Instant::now();
-
-    let count = merge_ranges(ranges).iter().map(|(s, e)| e - s + 1).sum();
-
-    println!("part 2: {count}");
-    println!("> Time elapsed is: {:?}", _start.elapsed());
-    Ok(count)
-}
+use aoc_common::Result;
+use aoc05::Day05;
 
 fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-
-    let (ranges, ids) = parse_input(input)?;
-
-    part1(&ranges, &ids)?;
-    part2(&ranges)?;
-    Ok(())
-}
-
-#[test]
-fn test_merge() -> Result<()> {
-    assert_eq!(merge_range((10, 15), (18, 22)), None);
-    assert_eq!(merge_range((10, 19), (18, 22)), Some((10, 22)));
-    assert_eq!(merge_range((10, 19), (12, 18)), Some((10, 19)));
-    assert_eq!(merge_range((10, 19), (1, 15)), Some((1, 19)));
-    assert_eq!(merge_range((10, 19), (1, 8)), None);
-    assert_eq!(merge_range((10, 20), (12, 18)), Some((10, 20)));
-    Ok(())
-}
-
-#[test]
-fn example_input() -> Result<()> {
-    let input = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
-    let (ranges, ids) = parse_input(input)?;
-    assert_eq!(part1(&ranges, &ids).unwrap(), 3);
-    assert_eq!(part2(&ranges).unwrap(), 14);
-    Ok(())
-}
-
-#[test]
-fn real_input() -> Result<()> {
-    let input = std::fs::read_to_string("input/input.txt").unwrap();
-    let (ranges, ids) = parse_input(input)?;
-    assert_eq!(part1(&ranges, &ids).unwrap(), 623);
-    assert_eq!(part2(&ranges).unwrap(), 353507173555373);
-    Ok(())
+    aoc_common::main::<Day05>()
 }
@@ -0,0 +1,171 @@
+//! Day 6: Trash Compactor, solving a worksheet of problems written in
+//! columns.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Answer, ParseError, ParseResult, Result, Solution, input};
+
+/// Parse the rows of numbers and the last row of operations, `+` or `*`, one
+/// per problem.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<(Vec<Vec<usize>>, Vec<String>)> {
+    let input = input.as_ref();
+    let lines: Vec<_> = input.lines().filter(|l| !l.trim().is_empty()).collect();
+    let Some((op_line, num_lines)) = lines.split_last() else {
+        return Err(ParseError::new(input, "a math worksheet").locate(input));
+    };
+
+    let ops = op_line
+        .split_whitespace()
+        .map(|op| match op {
+            "+" | "*" => Ok(op.to_string()),
+            _ => Err(ParseError::new(op, "an operation `+` or `*`")),
+        })
+        .collect::<ParseResult<Vec<_>>>()
+        .map_err(|e| e.locate(input))?;
+
+    let nums = num_lines
+        .iter()
+        .map(|l| {
+            let row = l
+                .split_whitespace()
+                .map(|n| input::parse::<usize>(n, "a number"))
+                .collect::<ParseResult<Vec<_>>>()?;
+            if row.len() != ops.len() {
+                return Err(ParseError::new(l, format!("{} numbers", ops.len())));
+            }
+            Ok(row)
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))?;
+
+    Ok((nums, ops))
+}
+
+/// Sum of the results of the problems, read row by row.
+pub fn part1(nums: &[Vec<usize>], ops: &[String]) -> Result<usize> {
+    let mut ans = 0;
+    for (i, op) in ops.iter().enumerate() {
+        if op == "+" {
+            ans += nums.iter().map(|n| n[i]).sum::<usize>()
+        } else {
+            ans += nums.iter().map(|n| n[i]).product::<usize>()
+        }
+    }
+
+    Ok(ans)
+}
+
+/// Sum of the results of the problems, read column by column from the right
+/// of the raw worksheet. Lines shorter than the widest one are taken as
+/// ending in spaces.
+pub fn part2<T: AsRef<str>>(input: T) -> Result<usize> {
+    let mut ans = 0;
+    let lines: Vec<_> = input
+        .as_ref()
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+    // 行尾的空格可能被编辑器删掉了，先补齐到最宽的一行再从右往左读
+    let lines: Vec<Vec<_>> = lines
+        .iter()
+        .map(|l| {
+            let mut row = l.as_bytes().to_vec();
+            row.resize(width, b' ');
+            row.reverse();
+            row
+        })
+        .collect();
+    let Some(op_row) = lines.len().checked_sub(1) else {
+        return Ok(ans);
+    };
+    let mut reals = vec![];
+
+    for i in 0..width {
+        let real = lines[0..op_row]
+            .iter()
+            .filter_map(|row| {
+                if row[i] == b' ' {
+                    None
+                } else {
+                    Some((row[i] - b'0') as usize)
+                }
+            })
+            .fold(0, |r, n| r * 10 + n);
+        match lines[op_row][i] {
+            b'+' => ans += reals.iter().sum::<usize>() + real,
+            b'*' => ans += reals.iter().product::<usize>() * real,
+            _ => {
+                if real == 0 {
+                    reals.clear();
+                } else {
+                    reals.push(real);
+                }
+            }
+        }
+    }
+
+    Ok(ans)
+}
+
+/// The solution of day 6 for the runner.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = (String, Vec<Vec<usize>>, Vec<String>);
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (nums, ops) = parse_input(input)?;
+        Ok((input.to_string(), nums, ops))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        part1(&input.1, &input.2).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        part2(&input.0).map(Answer::from)
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+    let (nums, ops) = parse_input(input)?;
+    assert_eq!(part1(&nums, &ops).unwrap(), 4277556);
+    assert_eq!(part2(input).unwrap(), 3263827);
+    Ok(())
+}
+
+#[test]
+fn ragged_lines() -> Result<()> {
+    let input = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +";
+    let (nums, ops) = parse_input(input)?;
+    assert_eq!(part1(&nums, &ops).unwrap(), 4277556);
+    assert_eq!(part2(input).unwrap(), 3263827);
+    assert_eq!(part2("1\n12\n+").unwrap(), 11 + 2);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day06::DAY)?;
+    aoc_common::registry::verify::<Day06>(&input)
+}
+
+#[test]
+fn parse_error() {
+    let e = parse_input("123 328\n 45 6a4\n*   +  ").unwrap_err();
+    assert_eq!((e.line(), e.column(), e.token()), (2, 5, "6a4"));
+    let e = parse_input("123 328\n 45 64\n*   -  ").unwrap_err();
+    assert_eq!((e.line(), e.column(), e.token()), (3, 5, "-"));
+}
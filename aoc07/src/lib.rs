@@ -0,0 +1,282 @@
+//! Day 7: Laboratories, following tachyon beams down a manifold of
+//! splitters.
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+
+use aoc_common::bitgrid::{self, BitGrid};
+use aoc_common::fuzz::Rng;
+use aoc_common::grid::{Grid, Pos};
+use aoc_common::{Alternate, Answer, Assumption, ParseError, ParseResult, Part, Result, Solution};
+
+/// The manifold, `S` for the start, `^` for a splitter and `.` for empty
+/// space.
+pub type Manifold = Grid<char>;
+
+/// Parse the manifold diagram, rows of equal length.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Manifold> {
+    let input = input.as_ref();
+    let grid = Grid::parse(input, "one of `.`, `S` or `^`", |c| {
+        matches!(c, '.' | 'S' | '^').then_some(c)
+    })
+    .map_err(|e| e.locate(input))?;
+    if grid.is_empty() {
+        return Err(ParseError::new(input, "a tachyon manifold diagram").locate(input));
+    }
+    Ok(grid)
+}
+
+/// The splitters `^` of the manifold.
+pub fn splitters(grid: &Manifold) -> BitGrid {
+    BitGrid::matching(grid, |&c| c == '^')
+}
+
+/// Times a beam is split on its way down, `splitters` being those of
+/// `grid`. The beams of a row are split 64 at a time.
+pub fn part1(grid: &Manifold, splitters: &BitGrid) -> Result<usize> {
+    let mut count = 0;
+    let mut beams = bitgrid::pack(grid.row(0).iter().map(|&c| c == 'S'));
+    let mut split = vec![0; beams.len()];
+
+    for row in splitters.rows().step_by(2) {
+        for ((split, beams), splitters) in split.iter_mut().zip(&beams).zip(row) {
+            *split = beams & splitters;
+            count += split.count_ones() as usize;
+        }
+        for (i, beams) in beams.iter_mut().enumerate() {
+            let (left, right) = bitgrid::shifted(&split, i);
+            *beams = *beams & !split[i] | left | right;
+        }
+    }
+
+    Ok(count)
+}
+
+/// [`part1`] one position at a time.
+pub fn part1_cells(grid: &Manifold) -> Result<usize> {
+    let mut count = 0;
+    let mut beams: Vec<_> = grid.row(0).iter().map(|c| c == &'S').collect();
+
+    for row in grid.rows().step_by(2) {
+        // 分裂出的光束到下一行才会再遇到分裂器，所以写到新的一行里
+        let mut next = vec![false; beams.len()];
+        for (j, _) in beams.iter().enumerate().filter(|(_, b)| **b) {
+            if row[j] == '^' {
+                next[j - 1] = true;
+                next[j + 1] = true;
+                count += 1;
+            } else {
+                next[j] = true;
+            }
+        }
+        beams = next;
+    }
+
+    Ok(count)
+}
+
+#[allow(unused)]
+fn dfs(current: Pos, grid: &Manifold, cache: &mut HashMap<Pos, usize>) -> usize {
+    if let Some(v) = cache.get(&current) {
+        return *v;
+    }
+    let Pos { row, col } = current;
+    let c = match grid[current] {
+        '.' | 'S' => {
+            if row + 1 < grid.height() {
+                dfs(Pos::new(row + 1, col), grid, cache)
+            } else {
+                1
+            }
+        }
+        '^' => dfs(Pos::new(row, col - 1), grid, cache) + dfs(Pos::new(row, col + 1), grid, cache),
+        _ => unreachable!(),
+    };
+    cache.insert(current, c);
+    c
+}
+
+/// Timelines of a single particle, which takes either way at every
+/// splitter.
+pub fn part2(grid: &Manifold) -> Result<usize> {
+    let mut timelines: Vec<_> = grid.row(0).iter().map(|c| (c == &'S') as usize).collect();
+
+    for row in grid.rows().step_by(2) {
+        let mut next = vec![0; timelines.len()];
+        for (j, current) in timelines.iter().enumerate().filter(|(_, c)| c > &&0) {
+            if row[j] == '^' {
+                next[j - 1] += current;
+                next[j + 1] += current;
+            } else {
+                next[j] += current;
+            }
+        }
+        timelines = next;
+    }
+    let count = timelines.iter().sum();
+
+    Ok(count)
+}
+
+/// Both parts only look at every other row, rows (from 0) are counted as in
+/// `step_by(2)`.
+fn splitters_on_even_rows(grid: &Manifold) -> std::result::Result<(), String> {
+    match grid.iter().find(|&(pos, &c)| pos.row % 2 == 1 && c == '^') {
+        Some((pos, _)) => Err(format!("splitter on {pos}")),
+        None => Ok(()),
+    }
+}
+
+fn start_on_first_row(grid: &Manifold) -> std::result::Result<(), String> {
+    if !grid.row(0).contains(&'S') {
+        return Err("no start on row 0".to_string());
+    }
+    match grid.iter().find(|&(pos, &c)| pos.row > 0 && c == 'S') {
+        Some((pos, _)) => Err(format!("start on {pos}")),
+        None => Ok(()),
+    }
+}
+
+/// A beam split on an edge would leave the manifold.
+fn splitters_inside(grid: &Manifold) -> std::result::Result<(), String> {
+    let last = grid.width() - 1;
+    let edge = |&(pos, &c): &(Pos, &char)| (pos.col == 0 || pos.col == last) && c == '^';
+    match grid.iter().find(edge) {
+        Some((pos, _)) => Err(format!("splitter on {pos}")),
+        None => Ok(()),
+    }
+}
+
+/// The solution of day 7 for the runner.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    const VERSION: u32 = 2;
+
+    type Input = (Manifold, BitGrid);
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = parse_input(input)?;
+        let splitters = splitters(&grid);
+        Ok((grid, splitters))
+    }
+
+    fn part1((grid, splitters): &Self::Input) -> Result<Answer> {
+        part1(grid, splitters).map(Answer::from)
+    }
+
+    fn part2((grid, _): &Self::Input) -> Result<Answer> {
+        part2(grid).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![Alternate {
+            part: Part::One,
+            name: "cells",
+            solve: |(grid, _)| part1_cells(grid).map(Answer::from),
+            slow: false,
+        }]
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Input>> {
+        vec![
+            Assumption {
+                name: "start on the first row only",
+                check: |(grid, _)| start_on_first_row(grid),
+            },
+            Assumption {
+                name: "splitters only on even rows",
+                check: |(grid, _)| splitters_on_even_rows(grid),
+            },
+            Assumption {
+                name: "no splitter on the edges",
+                check: |(grid, _)| splitters_inside(grid),
+            },
+        ]
+    }
+
+    /// Manifolds keeping to the assumptions: the start on the first row and
+    /// splitters on even rows away from the edges.
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let width = rng.index(3..=100);
+        let mut rows = vec![vec!['.'; width]; rng.index(1..=20) * 2];
+        rows[0][rng.index(1..=width - 2)] = 'S';
+        for row in rows.iter_mut().skip(2).step_by(2) {
+            for cell in &mut row[1..width - 1] {
+                if rng.chance(0.3) {
+                    *cell = '^';
+                }
+            }
+        }
+        let rows: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+        Some(rows.join("\n"))
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    let grid = parse_input(input)?;
+    assert_eq!(part1(&grid, &splitters(&grid)).unwrap(), 21);
+    assert_eq!(part1_cells(&grid).unwrap(), 21);
+    assert_eq!(part2(&grid).unwrap(), 40);
+    Ok(())
+}
+
+#[test]
+fn adjacent_splitters() -> Result<()> {
+    let grid = parse_input("...S...\n.......\n..^^^..\n.......\n..^.^..\n.......")?;
+    assert_eq!(part1(&grid, &splitters(&grid)).unwrap(), 3);
+    assert_eq!(part1_cells(&grid).unwrap(), 3);
+    Ok(())
+}
+
+#[test]
+fn assumptions() -> Result<()> {
+    let validate = |input| -> Result<Vec<_>> {
+        let parsed = aoc_common::Puzzle::of::<Day07>().parse(input)?;
+        Ok(parsed
+            .validate()
+            .into_iter()
+            .filter_map(|(_, r)| r.err())
+            .collect())
+    };
+    assert!(validate("..S..\n.....\n..^..\n.....")?.is_empty());
+    assert_eq!(
+        validate("..S..\n..^..\n^....\n.S...")?,
+        [
+            "start on row 3, column 1",
+            "splitter on row 1, column 2",
+            "splitter on row 2, column 0"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day07::DAY)?;
+    aoc_common::registry::verify::<Day07>(&input)
+}
@@ -0,0 +1,255 @@
+//! Day 8: Playground, connecting junction boxes closest first into circuits.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Answer, ParseError, ParseResult, Query, Result, Solution, err, input};
+use serde::{Deserialize, Serialize};
+
+/// Position of a junction box, `(x, y, z)`.
+pub type Coord = (usize, usize, usize);
+/// A pair of junction boxes by index.
+pub type Edge = (usize, usize);
+
+/// How many connections part 1 makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Params {
+    /// The closest pairs connected in part 1.
+    pub pairs: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { pairs: 1000 }
+    }
+}
+
+/// Parse one junction box `x,y,z` per line, with every pair of boxes sorted
+/// from closest to farthest.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<(Vec<Coord>, Vec<Edge>)> {
+    let input = input.as_ref();
+    let coords: Vec<Coord> = input::lines(input)
+        .map(|l| match input::comma_separated::<usize>(l)?[..] {
+            [x, y, z] => Ok((x, y, z)),
+            _ => Err(ParseError::new(l, "a junction box position `x,y,z`")),
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))?;
+    let connections = generate_connections(&coords);
+    Ok((coords, connections))
+}
+
+fn generate_connections(coords: &[Coord]) -> Vec<Edge> {
+    let mut edges = vec![];
+    for i in 0..coords.len() {
+        for j in i + 1..coords.len() {
+            edges.push((i, j));
+        }
+    }
+
+    edges.sort_unstable_by_key(|(a, b)| distance(coords[*a], coords[*b]));
+    edges
+}
+
+fn distance(c: Coord, other: Coord) -> usize {
+    let dx = c.0.abs_diff(other.0);
+    let dy = c.1.abs_diff(other.1);
+    let dz = c.2.abs_diff(other.2);
+    dx.pow(2) + dy.pow(2) + dz.pow(2)
+}
+
+/// Disjoint sets of the elements `0..n`, merged by size with path
+/// compression.
+///
+/// ```
+/// let mut uf = aoc08::UnionFind::new(4);
+/// assert!(uf.union(0, 1));
+/// assert!(uf.union(3, 1));
+/// assert!(!uf.union(0, 3));
+/// assert_eq!(uf.find(3), uf.find(0));
+/// assert_eq!((uf.get_size(1), uf.get_size(2)), (3, 1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// `n` sets of a single element each.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// The representative of the set holding `i`.
+    pub fn find(&mut self, i: usize) -> usize {
+        if self.parent[i] != i {
+            self.parent[i] = self.find(self.parent[i]);
+        }
+        self.parent[i]
+    }
+
+    /// Merge the sets of `i` and `j`, false when they already are one.
+    pub fn union(&mut self, i: usize, j: usize) -> bool {
+        let root_i = self.find(i);
+        let root_j = self.find(j);
+
+        if root_i != root_j {
+            if self.size[root_i] < self.size[root_j] {
+                self.parent[root_i] = root_j;
+                self.size[root_j] += self.size[root_i];
+            } else {
+                self.parent[root_j] = root_i;
+                self.size[root_i] += self.size[root_j];
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Size of the set holding `i`.
+    pub fn get_size(&mut self, i: usize) -> usize {
+        let root = self.find(i);
+        self.size[root]
+    }
+}
+
+/// Product of the sizes of the three largest circuits after connecting the
+/// closest `pairs` pairs.
+pub fn part1(coords: &[Coord], conns: &[Edge], pairs: usize) -> Result<usize> {
+    let n = coords.len();
+    let mut uf = UnionFind::new(n);
+    for &(u, v) in conns.iter().take(pairs) {
+        uf.union(u, v);
+    }
+
+    let mut sizes: Vec<_> = (0..n)
+        .filter(|&i| uf.parent[i] == i)
+        .map(|i| uf.size[i])
+        .collect();
+
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    let size = sizes.iter().take(3).product();
+
+    Ok(size)
+}
+
+/// Product of the x coordinates of the pair whose connection joins every box
+/// into one circuit, 0 when there is none.
+pub fn part2(coords: &[Coord], conns: &[Edge]) -> Result<usize> {
+    let n = coords.len();
+    let mut ans = 0;
+    let mut uf = UnionFind::new(n);
+    for &(u, v) in conns.iter() {
+        uf.union(u, v);
+
+        if uf.get_size(v) == n {
+            ans = coords[u].0 * coords[v].0;
+            break;
+        }
+    }
+
+    Ok(ans)
+}
+
+/// Index of a junction box given as a query argument.
+fn junction_box(coords: &[Coord], arg: &str) -> Result<usize> {
+    let i: usize = input::parse(arg, "a junction box index")?;
+    if i >= coords.len() {
+        return err!("no box {i}, there are {}", coords.len());
+    }
+    Ok(i)
+}
+
+/// The solution of day 8 for the runner.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = (Vec<Coord>, Vec<Edge>, Params);
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        let (coords, conns) = parse_input(input)?;
+        Ok((coords, conns, *params))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        part1(&input.0, &input.1, input.2.pairs).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        part2(&input.0, &input.1).map(Answer::from)
+    }
+
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![
+            Query {
+                name: "box",
+                usage: "BOX",
+                help: "position of a junction box, counted from 0 in input order",
+                run: |(coords, _, _), args| {
+                    let (x, y, z) = coords[junction_box(coords, args[0])?];
+                    Ok(format!("{x},{y},{z}"))
+                },
+            },
+            Query {
+                name: "pair",
+                usage: "N",
+                help: "the boxes of the N-th closest pair, counted from 1",
+                run: |(_, conns, _), args| {
+                    let n: usize = input::parse(args[0], "a pair number")?;
+                    match n.checked_sub(1).and_then(|i| conns.get(i)) {
+                        Some((u, v)) => Ok(format!("boxes {u} and {v}")),
+                        None => err!("no pair {n}, there are {}", conns.len()),
+                    }
+                },
+            },
+            Query {
+                name: "component-size",
+                usage: "BOX after PAIRS",
+                help: "boxes in the circuit of a box after connecting the closest pairs",
+                run: |(coords, conns, _), args| {
+                    let i = junction_box(coords, args[0])?;
+                    let pairs: usize = input::parse(args[2], "a number of pairs")?;
+                    let mut uf = UnionFind::new(coords.len());
+                    for &(u, v) in conns.iter().take(pairs) {
+                        uf.union(u, v);
+                    }
+                    Ok(uf.get_size(i).to_string())
+                },
+            },
+        ]
+    }
+}
+
+/// The answers of the example are checked with its fixture, see
+/// [`aoc_common::fixtures`].
+#[test]
+fn example_queries() -> Result<()> {
+    let path = aoc_common::fixtures::fixtures_dir(Day08::DAY).join("example-1.txt");
+    let input = std::fs::read_to_string(path)?;
+    let parsed = aoc_common::Puzzle::of::<Day08>().parse(&input)?;
+    let query = |name, args: &[&str]| parsed.query(name, args).unwrap();
+    assert_eq!(query("pair", &["1"])?, "boxes 0 and 19");
+    assert_eq!(query("box", &["19"])?, "425,690,689");
+    assert!(query("box", &["20"]).is_err());
+    assert_eq!(query("component-size", &["19", "after", "0"])?, "1");
+    assert_eq!(query("component-size", &["19", "after", "1"])?, "2");
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day08::DAY)?;
+    aoc_common::registry::verify::<Day08>(&input)
+}
@@ -0,0 +1,305 @@
+//! Day 9: Movie Theater, the largest rectangle with red tiles in opposite
+//! corners, anywhere or inside the loop of red and green tiles.
+
+#![warn(missing_docs)]
+
+use aoc_common::{Answer, Assumption, ParseError, ParseResult, Result, Solution, cancel, input};
+use rayon::prelude::*;
+
+/// Position of a red tile, `(x, y)`.
+pub type Point = (i128, i128);
+
+/// Parse one red tile `x,y` per line, in the order of the loop.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Vec<Point>> {
+    let input = input.as_ref();
+    input::lines(input)
+        .map(|l| match input::comma_separated::<i128>(l)?[..] {
+            [x, y] => Ok((x, y)),
+            _ => Err(ParseError::new(l, "a red tile position `x,y`")),
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))
+}
+
+/// Tiles of the rectangle with opposite corners `p` and `other`.
+pub fn area(p: Point, other: Point) -> u128 {
+    (1 + p.0.abs_diff(other.0)) * (p.1.abs_diff(other.1) + 1)
+}
+
+/// Largest rectangle with red tiles in opposite corners.
+pub fn part1(grid: &[Point]) -> Result<u128> {
+    let mut largest = 0;
+    for i in 0..grid.len() {
+        for j in i + 1..grid.len() {
+            largest = largest.max(area(grid[i], grid[j]))
+        }
+    }
+
+    Ok(largest)
+}
+
+fn cross_product(p: Point, a: Point, b: Point) -> i128 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+fn is_on_segment(p: Point, a: Point, b: Point) -> bool {
+    let min_x = a.0.min(b.0);
+    let max_x = a.0.max(b.0);
+    let min_y = a.1.min(b.1);
+    let max_y = a.1.max(b.1);
+    if p.0 < min_x || p.0 > max_x || p.1 < min_y || p.1 > max_y {
+        return false;
+    }
+
+    cross_product(p, a, b) == 0
+}
+
+/// Whether `p` is inside `polygon` or on its boundary, by casting a ray.
+///
+/// ```
+/// let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+/// assert!(aoc09::is_in_polygon((2, 2), &square));
+/// assert!(aoc09::is_in_polygon((4, 1), &square));
+/// assert!(!aoc09::is_in_polygon((5, 2), &square));
+/// ```
+pub fn is_in_polygon(p: Point, polygon: &[Point]) -> bool {
+    let mut inside = false;
+    let Some(mut j) = polygon.len().checked_sub(1) else {
+        return false;
+    };
+
+    for i in 0..polygon.len() {
+        let pi = polygon[i];
+        let pj = polygon[j];
+
+        if is_on_segment(p, pi, pj) {
+            return true;
+        }
+
+        if (pi.1 > p.1) != (pj.1 > p.1) {
+            let det = (pj.0 - pi.0) * (p.1 - pi.1) - (p.0 - pi.0) * (pj.1 - pi.1);
+            if pj.1 > pi.1 {
+                if det > 0 {
+                    inside = !inside;
+                }
+            } else if det < 0 {
+                inside = !inside;
+            }
+        }
+
+        j = i;
+    }
+    inside
+}
+
+fn is_proper_intersection(a: Point, b: Point, c: Point, d: Point) -> bool {
+    let d1 = cross_product(a, c, d);
+    let d2 = cross_product(b, c, d);
+    let d3 = cross_product(c, a, b);
+    let d4 = cross_product(d, a, b);
+
+    ((d1 > 0 && d2 < 0) || (d1 < 0 && d2 > 0)) && ((d3 > 0 && d4 < 0) || (d3 < 0 && d4 > 0))
+}
+
+#[allow(unused)]
+fn is_segment_in_polygon(p1: Point, p2: Point, polygon: &[Point]) -> bool {
+    if !is_in_polygon(p1, polygon) || !is_in_polygon(p2, polygon) {
+        return false;
+    }
+
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let poly_p1 = polygon[i];
+        let poly_p2 = polygon[j];
+        if is_proper_intersection(p1, p2, poly_p1, poly_p2) {
+            return false;
+        }
+        j = i;
+    }
+    true
+}
+
+/// Whether the rectangle with opposite corners `p1` and `p2` lies inside
+/// `polygon`: its corners are inside and its edges cross no edge of the
+/// polygon.
+///
+/// Example of failure:
+/// A "U" shaped polygon where the rectangle fills the gap of the "U" perfectly.
+/// Since the rectangle edges coincide with the polygon's "outer" edges without crossing them strictly,
+/// this function will return `true` (valid), even though the rectangle is topologically outside.
+pub fn is_rect_in_polygon(p1: Point, p2: Point, polygon: &[Point]) -> bool {
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+    let a = (x1.min(x2), y1.min(y2));
+    let b = (x1.min(x2), y1.max(y2));
+    let c = (x1.max(x2), y1.min(y2));
+    let d = (x1.max(x2), y1.max(y2));
+    if !is_in_polygon(a, polygon)
+        || !is_in_polygon(b, polygon)
+        || !is_in_polygon(c, polygon)
+        || !is_in_polygon(d, polygon)
+    {
+        return false;
+    }
+
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let poly_p1 = polygon[i];
+        let poly_p2 = polygon[j];
+        if is_proper_intersection(a, b, poly_p1, poly_p2)
+            || is_proper_intersection(b, c, poly_p1, poly_p2)
+            || is_proper_intersection(c, d, poly_p1, poly_p2)
+            || is_proper_intersection(d, a, poly_p1, poly_p2)
+        {
+            return false;
+        }
+        j = i;
+    }
+    true
+}
+
+#[allow(dead_code)]
+fn is_rect_in_polygon_with_aabb(p1: Point, p2: Point, polygon: &[Point]) -> bool {
+    // **DEPRECATED (Known Issues):** This function currently uses an incorrect
+    // method for polygon filling, as it fails to prevent external rectangles
+    // from being mistakenly included in concave gaps.
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+
+    let rx_min = x1.min(x2);
+    let rx_max = x1.max(x2);
+    let ry_min = y1.min(y2);
+    let ry_max = y1.max(y2);
+
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let poly_p1 = polygon[i];
+        let poly_p2 = polygon[j];
+        let px_min = poly_p1.0.min(poly_p2.0);
+        let px_max = poly_p1.0.max(poly_p2.0);
+        let py_min = poly_p1.1.min(poly_p2.1);
+        let py_max = poly_p1.1.max(poly_p2.1);
+
+        if px_min == px_max {
+            let p_x = px_min;
+            if p_x > rx_min && p_x < rx_max && !(py_max <= ry_min || py_min >= ry_max) {
+                return false;
+            }
+        }
+
+        if py_min == py_max {
+            let p_y = py_min;
+            if p_y > ry_min && p_y < ry_max && !(px_max <= rx_min || px_min >= rx_max) {
+                return false;
+            }
+        }
+
+        j = i;
+    }
+    true
+}
+
+/// Largest rectangle with red tiles in opposite corners inside the loop.
+pub fn part2(grid: &[Point]) -> Result<u128> {
+    let token = cancel::current();
+    let largest = (0..grid.len())
+        .into_par_iter()
+        .map(|i| {
+            let mut local_largest = 0;
+            if token.is_cancelled() {
+                return local_largest;
+            }
+            for j in i + 1..grid.len() {
+                if area(grid[i], grid[j]) <= local_largest {
+                    continue;
+                }
+                if is_rect_in_polygon(grid[i], grid[j], grid) {
+                    local_largest = local_largest.max(area(grid[i], grid[j]));
+                }
+            }
+            local_largest
+        })
+        .max()
+        .unwrap_or(0);
+    token.check()?;
+
+    Ok(largest)
+}
+
+/// `is_rect_in_polygon` only handles edges along the axes.
+fn rectilinear(grid: &[Point]) -> std::result::Result<(), String> {
+    for (i, &p) in grid.iter().enumerate() {
+        let q = grid[(i + 1) % grid.len()];
+        if p.0 != q.0 && p.1 != q.1 {
+            return Err(format!(
+                "diagonal edge from {},{} to {},{}",
+                p.0, p.1, q.0, q.1
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The solution of day 9 for the runner.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Point>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<Answer> {
+        part1(grid).map(Answer::from)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<Answer> {
+        part2(grid).map(Answer::from)
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Input>> {
+        vec![Assumption {
+            name: "rectilinear polygon",
+            check: |grid| rectilinear(grid),
+        }]
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+    let grid = parse_input(input)?;
+    assert_eq!(part2(&grid).unwrap(), 24);
+    Ok(())
+}
+
+#[test]
+fn assumptions() -> Result<()> {
+    let grid = parse_input("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3")?;
+    assert_eq!(rectilinear(&grid), Ok(()));
+    let grid = parse_input("7,1\n11,1\n11,7\n9,5\n2,5\n2,3\n7,3")?;
+    assert_eq!(
+        rectilinear(&grid),
+        Err("diagonal edge from 11,7 to 9,5".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day09::DAY)?;
+    aoc_common::registry::verify::<Day09>(&input)
+}
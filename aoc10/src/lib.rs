@@ -0,0 +1,450 @@
+//! Day 10: Factory, the fewest button presses that configure the indicator
+//! lights or the joltage counters of every machine.
+
+#![warn(missing_docs)]
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+
+use aoc_common::cancel::{self, Cancelled, Token};
+use aoc_common::fuzz::Rng;
+use aoc_common::{Alternate, Answer, ParseError, ParseResult, Part, Result, Solution, input};
+use good_lp::{
+    Expression, Solution as _, SolverModel, Variable, default_solver, variable, variables,
+};
+
+/// One line of the manual: `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+#[derive(Debug)]
+pub struct Machine {
+    lights: u128,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<usize>,
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        let mut lights = None;
+        let mut buttons = vec![];
+        let mut joltage = None;
+        for p in s.split_whitespace() {
+            if let Some(inner) = p.strip_prefix("[") {
+                let inner = inner
+                    .strip_suffix("]")
+                    .ok_or_else(|| ParseError::new(p, "a light diagram like `[.##.]`"))?;
+                if inner.len() > 128 {
+                    return Err(ParseError::new(p, "at most 128 lights"));
+                }
+                let mut diagram = 0;
+                for (i, c) in inner.char_indices() {
+                    match c {
+                        '#' => diagram |= 1 << i,
+                        '.' => {}
+                        _ => {
+                            return Err(ParseError::new(&inner[i..i + c.len_utf8()], "`.` or `#`"));
+                        }
+                    }
+                }
+                lights = Some((diagram, inner.len()));
+            } else if let Some(inner) = p.strip_prefix("(") {
+                let inner = inner
+                    .strip_suffix(")")
+                    .ok_or_else(|| ParseError::new(p, "a button wiring like `(1,3)`"))?;
+                buttons.push((p, input::comma_separated::<usize>(inner)?));
+            } else if let Some(inner) = p.strip_prefix("{") {
+                let inner = inner
+                    .strip_suffix("}")
+                    .ok_or_else(|| ParseError::new(p, "joltage requirements like `{3,5,4,7}`"))?;
+                joltage = Some((p, input::comma_separated::<usize>(inner)?));
+            } else {
+                return Err(ParseError::new(
+                    p,
+                    "lights `[...]`, a button `(...)` or joltage `{...}`",
+                ));
+            }
+        }
+
+        let (lights, count) =
+            lights.ok_or_else(|| ParseError::new(s, "a machine starting with its lights"))?;
+        let (token, joltage) = joltage
+            .ok_or_else(|| ParseError::new(&s[s.len()..], "joltage requirements `{...}`"))?;
+        if joltage.len() != count {
+            return Err(ParseError::new(
+                token,
+                format!("{count} joltage requirements"),
+            ));
+        }
+        if let Some((token, _)) = buttons.iter().find(|(_, b)| b.iter().any(|&i| i >= count)) {
+            return Err(ParseError::new(
+                token,
+                format!("lights between 0 and {}", count - 1),
+            ));
+        }
+
+        Ok(Self {
+            lights,
+            buttons: buttons.into_iter().map(|(_, b)| b).collect(),
+            joltage,
+        })
+    }
+}
+
+/// Parse one machine per line.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Vec<Machine>> {
+    let input = input.as_ref();
+    input::lines(input)
+        .map(|l| l.parse())
+        .collect::<ParseResult<_>>()
+        .map_err(|e| e.locate(input))
+}
+
+fn press_button(mut lights: u128, button: &[usize]) -> u128 {
+    for b in button {
+        let mask = 1 << b;
+        lights ^= mask;
+    }
+    lights
+}
+
+impl Machine {
+    /// Lights that have to be on, light `i` as bit `i`.
+    pub fn lights(&self) -> u128 {
+        self.lights
+    }
+
+    /// The lights, or counters, each button toggles.
+    pub fn buttons(&self) -> &[Vec<usize>] {
+        &self.buttons
+    }
+
+    /// Joltage every counter has to reach.
+    pub fn joltage(&self) -> &[usize] {
+        &self.joltage
+    }
+
+    /// Fewest presses that turn on the lights, `None` when no presses do.
+    pub fn min_presses_for_lights(&self) -> Option<usize> {
+        // BFS
+
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+
+        queue.push_back((self.lights, 0, 0));
+        visited.insert(self.lights);
+
+        while let Some((current_lights, button_pressed, presses)) = queue.pop_front() {
+            if current_lights == 0 {
+                return Some(presses);
+            }
+            for (index, button) in self.buttons.iter().enumerate() {
+                if button_pressed & 1 << index == 1 {
+                    continue;
+                }
+                let next_lights = press_button(current_lights, button);
+                let button_pressed = button_pressed | 1 << index;
+                if visited.insert(next_lights) {
+                    queue.push_back((next_lights, button_pressed | 1 << index, presses + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn min_presses_for_joltage_bfs(
+        &self,
+        token: &Token,
+    ) -> std::result::Result<Option<usize>, Cancelled> {
+        // BFS
+
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+
+        queue.push_back((self.joltage.clone(), vec![None; self.buttons.len()], 0));
+
+        // levels are the number of buttons decided, not the number of presses,
+        // so the first solution found is not necessarily the smallest
+        let mut min_presses = None;
+        while let Some((counter, button_pressed, presses)) = queue.pop_front() {
+            token.check()?;
+            if visited.insert(button_pressed.to_vec()) {
+                for (index, button) in self.buttons.iter().enumerate() {
+                    if button_pressed[index].is_some() {
+                        continue;
+                    }
+                    let max_p = max_press(&counter, button);
+                    for p in 0..=max_p {
+                        let mut new_counter = counter.to_vec();
+                        let mut new_button_pressed = button_pressed.to_vec();
+                        new_button_pressed[index] = Some(p);
+                        for &b in button {
+                            new_counter[b] -= p;
+                        }
+                        if new_counter.iter().all(|j| j == &0) {
+                            let total = presses + p;
+                            min_presses = Some(min_presses.map_or(total, |m: usize| m.min(total)));
+                        } else {
+                            queue.push_back((
+                                new_counter.to_vec(),
+                                new_button_pressed.to_vec(),
+                                presses + p,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(min_presses)
+    }
+
+    fn min_presses_for_joltage_dfs(
+        &self,
+        counter: &[usize],
+        button_pressed: &[Option<usize>],
+        cache: &mut HashMap<Vec<Option<usize>>, usize>,
+        token: &Token,
+    ) -> std::result::Result<Option<usize>, Cancelled> {
+        token.check()?;
+        if counter.iter().all(|j| j == &0) {
+            return Ok(Some(0));
+        }
+        if button_pressed.iter().all(|b| b.is_some()) {
+            return Ok(None);
+        }
+        if let Some(p) = cache.get(button_pressed) {
+            if *p == usize::MAX {
+                return Ok(None);
+            }
+            return Ok(Some(*p));
+        }
+        let mut min_presses = usize::MAX;
+        for (b_index, button) in self.buttons.iter().enumerate() {
+            if button_pressed[b_index].is_some() {
+                // pressed
+                continue;
+            }
+            let max_p = max_press(counter, button);
+            for p in 0..=max_p {
+                let mut new_counter = counter.to_vec();
+                let mut new_button_pressed = button_pressed.to_vec();
+                new_button_pressed[b_index] = Some(p);
+                for &b in button {
+                    new_counter[b] -= p;
+                }
+                if let Some(pressed) = self.min_presses_for_joltage_dfs(
+                    &new_counter,
+                    &new_button_pressed,
+                    cache,
+                    token,
+                )? {
+                    min_presses = min_presses.min(p + pressed);
+                }
+            }
+        }
+        cache.insert(button_pressed.to_vec(), min_presses);
+        if min_presses != usize::MAX {
+            Ok(Some(min_presses))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Fewest presses that bring every counter to its joltage, as an integer
+    /// program, an error when the solver finds none.
+    pub fn min_presses_for_joltage_good_lp(&self) -> Result<usize> {
+        let f_count = self.joltage.len();
+        let b_count = self.buttons.len();
+        let mut f = vec![vec![0; b_count]; f_count];
+        for (index, button) in self.buttons.iter().enumerate() {
+            for &b in button {
+                f[b][index] = 1;
+            }
+        }
+        let mut problem = variables!();
+        let vars = vec![variable().min(0).integer(); b_count];
+        let t: Vec<Variable> = problem.add_all(vars);
+        let objective: Expression = t.iter().sum();
+        let mut model = problem.minimise(&objective).using(default_solver);
+
+        for (row, &j) in f.iter().zip(&self.joltage) {
+            let mut constraint: Expression = Expression::from(0);
+            for (&coeff, &var) in row.iter().zip(&t) {
+                if coeff == 1 {
+                    constraint += var;
+                }
+            }
+            model = model.with(constraint.eq(j as f64))
+        }
+        let sol = model.solve().map_err(|e| format!("solver error: {e}"))?;
+        Ok(sol.eval(objective).round() as usize)
+    }
+}
+
+fn max_press(joltage: &[usize], button: &[usize]) -> usize {
+    let mut m = usize::MAX;
+    for &b in button.iter() {
+        m = m.min(joltage[b]);
+    }
+    m
+}
+
+/// Fewest presses configuring the lights of every machine.
+pub fn part1(machines: &[Machine]) -> Result<usize> {
+    let ans = machines
+        .iter()
+        .filter_map(|m| m.min_presses_for_lights())
+        .sum();
+
+    Ok(ans)
+}
+
+/// Fewest presses configuring the joltage of every machine.
+pub fn part2(machines: &[Machine]) -> Result<usize> {
+    let token = cancel::current();
+    let mut ans = 0;
+    for (i, m) in machines.iter().enumerate() {
+        token.check()?;
+        ans += m
+            .min_presses_for_joltage_good_lp()
+            .map_err(|e| format!("machine {}: {e}", i + 1))?;
+    }
+
+    Ok(ans)
+}
+
+/// [`part2`] by a breadth-first search over the counters.
+pub fn part2_bfs(machines: &[Machine]) -> Result<usize> {
+    let token = cancel::current();
+    let mut ans = 0;
+    for m in machines {
+        ans += m.min_presses_for_joltage_bfs(&token)?.unwrap_or(0);
+    }
+
+    Ok(ans)
+}
+
+/// [`part2`] by a depth-first search over the presses of each button.
+pub fn part2_dfs(machines: &[Machine]) -> Result<usize> {
+    let token = cancel::current();
+    let mut ans = 0;
+    for m in machines {
+        let button_pressed = vec![None; m.buttons.len()];
+        ans += m
+            .min_presses_for_joltage_dfs(&m.joltage, &button_pressed, &mut HashMap::new(), &token)?
+            .unwrap_or(0);
+    }
+
+    Ok(ans)
+}
+
+/// The solution of day 10 for the runner.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<Answer> {
+        part1(machines).map(Answer::from)
+    }
+
+    fn part2(machines: &Self::Input) -> Result<Answer> {
+        part2(machines).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![
+            Alternate {
+                part: Part::Two,
+                name: "bfs",
+                solve: |machines| part2_bfs(machines).map(Answer::from),
+                slow: true,
+            },
+            Alternate {
+                part: Part::Two,
+                name: "dfs",
+                solve: |machines| part2_dfs(machines).map(Answer::from),
+                slow: true,
+            },
+        ]
+    }
+
+    fn cases(input: &str) -> Vec<&str> {
+        input::lines(input).collect()
+    }
+
+    /// Machines small enough for the BFS and DFS, the joltage is reached by
+    /// pressing every button a random number of times.
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let machines: Vec<String> = (0..rng.index(1..=3))
+            .map(|_| {
+                let count = rng.index(2..=5);
+                let lights: String = (0..count)
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect();
+                let mut joltage = vec![0; count];
+                let buttons: Vec<String> = (0..rng.index(1..=5))
+                    .map(|_| {
+                        let mut wiring: Vec<usize> =
+                            (0..count).filter(|_| rng.chance(0.5)).collect();
+                        if wiring.is_empty() {
+                            wiring.push(rng.index(0..=count - 1));
+                        }
+                        let presses = rng.index(0..=3);
+                        for &i in &wiring {
+                            joltage[i] += presses;
+                        }
+                        let wiring: Vec<String> = wiring.iter().map(|i| i.to_string()).collect();
+                        format!("({})", wiring.join(","))
+                    })
+                    .collect();
+                let joltage: Vec<String> = joltage.iter().map(|j| j.to_string()).collect();
+                format!("[{lights}] {} {{{}}}", buttons.join(" "), joltage.join(","))
+            })
+            .collect();
+        Some(machines.join("\n"))
+    }
+}
+
+#[test]
+fn example_input() -> Result<()> {
+    let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+    let machines = parse_input(input)?;
+    assert_eq!(part1(&machines).unwrap(), 7);
+    assert_eq!(part2(&machines).unwrap(), 33);
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day10::DAY)?;
+    aoc_common::registry::verify::<Day10>(&input)
+}
+
+#[test]
+fn parse_error() {
+    let e = parse_input("[.##.] (3) (1,3) (2) {3,5,4,7}\n[.#] (0,2) {1,2}").unwrap_err();
+    assert_eq!((e.line(), e.column(), e.token()), (2, 6, "(0,2)"));
+    let e = parse_input("[.##.] (3) (1,x) {3,5,4,7}").unwrap_err();
+    assert_eq!((e.line(), e.column(), e.token()), (1, 15, "x"));
+}
+
+#[test]
+fn solver_error() -> Result<()> {
+    let machines = parse_input("[.#] (0) (0,1) {2,1}\n[..] (0) {1,2}")?;
+    let e = part2(&machines).unwrap_err();
+    assert!(e.to_string().starts_with("machine 2: solver error: "), "{e}");
+    Ok(())
+}
@@ -0,0 +1,477 @@
+//! Day 11: Reactor, counting the paths between devices of a directed graph.
+
+#![warn(missing_docs)]
+
+use std::collections::HashMap;
+
+use aoc_common::fuzz::Rng;
+use aoc_common::{
+    Alternate, Answer, Assumption, ParseError, ParseResult, Part, Query, Result, Solution, input,
+};
+use serde::{Deserialize, Serialize};
+
+/// Names of the devices the paths run between.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Params {
+    /// Start of the paths of part 1.
+    pub you: String,
+    /// Start of the paths of part 2.
+    pub svr: String,
+    /// Device every path of part 2 visits.
+    pub dac: String,
+    /// Other device every path of part 2 visits.
+    pub fft: String,
+    /// End of every path.
+    pub out: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            you: "you".to_string(),
+            svr: "svr".to_string(),
+            dac: "dac".to_string(),
+            fft: "fft".to_string(),
+            out: "out".to_string(),
+        }
+    }
+}
+
+/// The devices by id, with the devices each one outputs to.
+#[derive(Debug)]
+pub struct Connections {
+    device_table: HashMap<String, usize>,
+    adj_list: Vec<Vec<usize>>,
+}
+
+impl Connections {
+    fn new() -> Self {
+        Self {
+            device_table: HashMap::new(),
+            adj_list: Vec::new(),
+        }
+    }
+
+    fn add_connection(&mut self, line: &str) -> ParseResult<()> {
+        if let Some((input, outputs)) = line.split_once(":") {
+            let input = input.trim();
+            if input.is_empty() || input.contains(char::is_whitespace) {
+                return Err(ParseError::new(input, "a device name"));
+            }
+            let input_id = self.insert_device(input);
+            let output_ids = outputs
+                .split_whitespace()
+                .map(|d| self.insert_device(d))
+                .collect();
+            if input_id >= self.adj_list.len() {
+                self.adj_list.resize(input_id + 1, vec![]);
+            }
+            self.adj_list[input_id] = output_ids;
+            return Ok(());
+        }
+        Err(ParseError::new(line, "a connection like `you: bbb ccc`"))
+    }
+
+    fn insert_device(&mut self, dev: &str) -> usize {
+        if let Some(id) = self.device_table.get(dev) {
+            *id
+        } else {
+            let id = self.device_table.len();
+            self.device_table.insert(dev.to_string(), id);
+            id
+        }
+    }
+
+    /// Id of the device named `dev`.
+    pub fn get_id(&self, dev: &str) -> Option<usize> {
+        self.device_table.get(dev).copied()
+    }
+
+    /// Ids of the devices `id` outputs to.
+    pub fn get_outputs(&self, id: usize) -> &[usize] {
+        if id < self.adj_list.len() {
+            &self.adj_list[id]
+        } else {
+            &[]
+        }
+    }
+}
+
+/// Names of the devices by id.
+fn names(connections: &Connections) -> Vec<&str> {
+    let mut names = vec![""; connections.device_table.len()];
+    for (name, &id) in &connections.device_table {
+        names[id] = name;
+    }
+    names
+}
+
+/// Check that the devices form no cycle, or name one.
+///
+/// Path counting recurses without tracking the path, a cycle would never end.
+pub fn acyclic(connections: &Connections) -> std::result::Result<(), String> {
+    let devices = connections.device_table.len();
+    let names = names(connections);
+    // 0 unvisited, 1 on the current path, 2 done
+    let mut state = vec![0u8; devices];
+    for start in 0..devices {
+        if state[start] != 0 {
+            continue;
+        }
+        state[start] = 1;
+        let mut path = vec![(start, 0)];
+        while let Some((current, i)) = path.last_mut() {
+            let current = *current;
+            let Some(&next) = connections.get_outputs(current).get(*i) else {
+                state[current] = 2;
+                path.pop();
+                continue;
+            };
+            *i += 1;
+            match state[next] {
+                0 => {
+                    state[next] = 1;
+                    path.push((next, 0));
+                }
+                1 => {
+                    let from = path.iter().position(|&(id, _)| id == next).unwrap();
+                    let cycle: Vec<&str> = path[from..]
+                        .iter()
+                        .map(|&(id, _)| names[id])
+                        .chain([names[next]])
+                        .collect();
+                    return Err(format!("cycle {}", cycle.join(" -> ")));
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parse one device per line with the devices it outputs to, `aaa: bbb ccc`.
+pub fn parse_input<T: AsRef<str>>(input: T) -> ParseResult<Connections> {
+    let input = input.as_ref();
+    let mut connections = Connections::new();
+    for line in input::lines(input) {
+        connections
+            .add_connection(line)
+            .map_err(|e| e.locate(input))?;
+    }
+    Ok(connections)
+}
+
+fn count_paths_dfs(
+    current: usize,
+    target: usize,
+    connections: &Connections,
+    cache: &mut [Option<usize>],
+) -> usize {
+    if current == target {
+        return 1;
+    }
+    if let Some(count) = cache[current] {
+        return count;
+    }
+
+    let mut count = 0;
+    for &next in connections.get_outputs(current) {
+        count += count_paths_dfs(next, target, connections, cache);
+    }
+    cache[current] = Some(count);
+    count
+}
+
+fn node(connections: &Connections, name: &str) -> Result<usize> {
+    connections
+        .get_id(name)
+        .ok_or_else(|| format!("node '{name}' not found").into())
+}
+
+/// Paths from `you` to `out`.
+pub fn part1(connections: &Connections, params: &Params) -> Result<usize> {
+    let you = node(connections, &params.you)?;
+    let out = node(connections, &params.out)?;
+
+    let count = count_paths_dfs(
+        you,
+        out,
+        connections,
+        &mut vec![None; connections.device_table.len()],
+    );
+
+    Ok(count)
+}
+
+fn count_paths_with_dac_fft(
+    current: usize,
+    target: usize,
+    visited_mask: u8,
+    dac_fft: &[usize],
+    connections: &Connections,
+    cache: &mut [Option<usize>],
+) -> usize {
+    if visited_mask == 3 && current == target {
+        return 1;
+    }
+    if let Some(count) = cache[current * 4 + visited_mask as usize] {
+        return count;
+    }
+    let mut count = 0;
+    for &next in connections.get_outputs(current) {
+        let next_mask = visited_mask
+            | if next == dac_fft[0] {
+                1
+            } else if next == dac_fft[1] {
+                2
+            } else {
+                0
+            };
+        count += count_paths_with_dac_fft(next, target, next_mask, dac_fft, connections, cache);
+    }
+    cache[current * 4 + visited_mask as usize] = Some(count);
+    count
+}
+
+/// Paths from `svr` to `out` through both `dac` and `fft`.
+pub fn part2(connections: &Connections, params: &Params) -> Result<usize> {
+    let svr = node(connections, &params.svr)?;
+    let out = node(connections, &params.out)?;
+    let dac = node(connections, &params.dac)?;
+    let fft = node(connections, &params.fft)?;
+
+    let count = count_paths_with_dac_fft(
+        svr,
+        out,
+        0,
+        &[dac, fft],
+        connections,
+        &mut vec![None; connections.device_table.len() * 4],
+    );
+
+    Ok(count)
+}
+
+/// [`part2`] multiplying the paths between the devices in either order.
+pub fn part2_segmented(connections: &Connections, params: &Params) -> Result<usize> {
+    let svr = node(connections, &params.svr)?;
+    let out = node(connections, &params.out)?;
+    let dac = node(connections, &params.dac)?;
+    let fft = node(connections, &params.fft)?;
+
+    let count_between = |start, end| {
+        count_paths_dfs(
+            start,
+            end,
+            connections,
+            &mut vec![None; connections.device_table.len()],
+        )
+    };
+
+    let mut count = 0;
+    // svr -> dac -> fft -> out
+    let dac_fft = count_between(dac, fft);
+    if dac_fft != 0 {
+        let svr_dac = count_between(svr, dac);
+        let fft_out = count_between(fft, out);
+        count = svr_dac * dac_fft * fft_out;
+    }
+    // svt -> fft -> dac -> out
+    let fft_dac = count_between(fft, dac);
+    if fft_dac != 0 {
+        let svr_fft = count_between(svr, fft);
+        let dac_out = count_between(dac, out);
+        count += svr_fft * fft_dac * dac_out;
+    }
+    Ok(count)
+}
+
+/// The solution of day 11 for the runner.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = (Connections, Params);
+
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        Ok((parse_input(input)?, params.clone()))
+    }
+
+    fn part1((connections, params): &Self::Input) -> Result<Answer> {
+        part1(connections, params).map(Answer::from)
+    }
+
+    fn part2((connections, params): &Self::Input) -> Result<Answer> {
+        part2(connections, params).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![Alternate {
+            part: Part::Two,
+            name: "segmented",
+            solve: |(connections, params)| part2_segmented(connections, params).map(Answer::from),
+            slow: false,
+        }]
+    }
+
+    fn queries() -> Vec<Query<Self::Input>> {
+        vec![
+            Query {
+                name: "paths",
+                usage: "FROM TO",
+                help: "paths from one device to another",
+                run: |(connections, _), args| {
+                    acyclic(connections)?;
+                    let from = node(connections, args[0])?;
+                    let to = node(connections, args[1])?;
+                    let mut cache = vec![None; connections.device_table.len()];
+                    Ok(count_paths_dfs(from, to, connections, &mut cache).to_string())
+                },
+            },
+            Query {
+                name: "outputs",
+                usage: "DEVICE",
+                help: "devices a device outputs to",
+                run: |(connections, _), args| {
+                    let names = names(connections);
+                    let outputs: Vec<&str> = connections
+                        .get_outputs(node(connections, args[0])?)
+                        .iter()
+                        .map(|&id| names[id])
+                        .collect();
+                    Ok(outputs.join(" "))
+                },
+            },
+            Query {
+                name: "inputs",
+                usage: "DEVICE",
+                help: "devices that output to a device",
+                run: |(connections, _), args| {
+                    let id = node(connections, args[0])?;
+                    let names = names(connections);
+                    let mut inputs: Vec<&str> = (0..names.len())
+                        .filter(|&input| connections.get_outputs(input).contains(&id))
+                        .map(|input| names[input])
+                        .collect();
+                    inputs.sort_unstable();
+                    Ok(inputs.join(" "))
+                },
+            },
+        ]
+    }
+
+    fn assumptions() -> Vec<Assumption<Self::Input>> {
+        vec![Assumption {
+            name: "acyclic graph",
+            check: |(connections, _)| acyclic(connections),
+        }]
+    }
+
+    /// A random DAG: devices only connect to later devices, from `svr` and
+    /// `you` first to `out` last, with `dac` and `fft` somewhere in between.
+    fn generate(rng: &mut Rng) -> Option<String> {
+        let mut devices: Vec<String> = (0..rng.index(2..=8))
+            .map(|i| {
+                let letter = |n: usize| char::from(b'a' + (n % 26) as u8);
+                format!("{}{}{}", letter(i / 26), letter(i), letter(i + 7))
+            })
+            .collect();
+        for name in ["dac", "fft"] {
+            let at = rng.index(0..=devices.len());
+            devices.insert(at, name.to_string());
+        }
+        devices.splice(0..0, ["svr".to_string(), "you".to_string()]);
+        devices.push("out".to_string());
+
+        let lines: Vec<String> = (0..devices.len() - 1)
+            .map(|i| {
+                let mut outputs: Vec<&str> = (i + 1..devices.len())
+                    .filter(|_| rng.chance(0.4))
+                    .map(|j| devices[j].as_str())
+                    .collect();
+                if outputs.is_empty() {
+                    outputs.push(&devices[rng.index(i + 1..=devices.len() - 1)]);
+                }
+                format!("{}: {}", devices[i], outputs.join(" "))
+            })
+            .collect();
+        Some(lines.join("\n"))
+    }
+}
+
+#[test]
+fn example_input_part1() -> Result<()> {
+    let input = "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+    let connections = parse_input(input)?;
+    assert_eq!(part1(&connections, &Params::default()).unwrap(), 5);
+    let params = Params {
+        you: "ccc".to_string(),
+        ..Params::default()
+    };
+    assert_eq!(part1(&connections, &params).unwrap(), 3);
+
+    let parsed = aoc_common::Puzzle::of::<Day11>().parse(input)?;
+    let query = |name, args: &[&str]| parsed.query(name, args).unwrap();
+    assert_eq!(query("paths", &["ccc", "out"])?, "3");
+    assert_eq!(query("outputs", &["ccc"])?, "ddd eee fff");
+    assert_eq!(query("inputs", &["fff"])?, "ccc hhh");
+    assert!(query("paths", &["zzz", "out"]).is_err());
+    Ok(())
+}
+
+#[test]
+fn example_input_part2() -> Result<()> {
+    let input = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+    let connections = parse_input(input)?;
+    let params = Params::default();
+    assert_eq!(part2(&connections, &params).unwrap(), 2);
+    assert_eq!(part2_segmented(&connections, &params).unwrap(), 2);
+    Ok(())
+}
+
+#[test]
+fn assumptions() -> Result<()> {
+    let connections = parse_input("you: aaa bbb\naaa: out\nbbb: aaa")?;
+    assert_eq!(acyclic(&connections), Ok(()));
+    let connections = parse_input("you: aaa\naaa: bbb out\nbbb: ccc\nccc: aaa")?;
+    assert_eq!(
+        acyclic(&connections),
+        Err("cycle aaa -> bbb -> ccc -> aaa".to_string())
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(missing_input, ignore = "no puzzle input, see AOC_INPUT_DIR")]
+fn real_input() -> Result<()> {
+    let input = aoc_common::read_input(Day11::DAY)?;
+    aoc_common::registry::verify::<Day11>(&input)
+}